@@ -1,8 +1,9 @@
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, Shell};
-use dsync::{error::IOErrorToError, GenerationConfig, TableOptions};
-use dsync::{BytesType, FileChangeStatus, GenerationConfigOpts, StringType};
-use std::collections::HashMap;
+use dsync::{error::IOErrorToError, ConfigFile, GenerationConfig};
+use dsync::{
+    BytesType, FileChange, FileChangeStatus, NetworkAddressType, ReturningMode, StringType,
+};
 use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 
@@ -49,6 +50,25 @@ pub struct MainOptions {
     #[arg(short = 'o', long = "output")]
     pub output: PathBuf,
 
+    /// Load generation options from a toml config file (like "dsync.toml")
+    ///
+    /// The file may contain a "[default]" section and a "[tables.<name>]" section per table.
+    /// Options given on the command-line override the "[default]" section and global options of the file.
+    #[arg(long = "config")]
+    pub config: Option<PathBuf>,
+
+    /// Do not write anything, only check if the generated files are up-to-date
+    ///
+    /// Exits with a non-0 exit code and lists all files that would be modified or deleted if they are not up-to-date
+    #[arg(long = "check")]
+    pub check: bool,
+
+    /// Do not write anything, instead print a unified diff of all changes that would be made
+    ///
+    /// Can be combined with "--check"
+    #[arg(long = "diff")]
+    pub diff: bool,
+
     /// adds the #[tsync] attribute to all structs; see https://github.com/Wulf/tsync
     #[arg(long = "tsync")]
     #[cfg(feature = "tsync")]
@@ -63,6 +83,14 @@ pub struct MainOptions {
     #[arg(short = 'g', long = "autogenerated-columns")]
     pub autogenerated_columns: Option<Vec<String>>,
 
+    /// List of columns which can only be set on create and are not part of the Update struct (for example: "created_at", "owner_id", etc.)
+    #[arg(long = "immutable-columns")]
+    pub immutable_columns: Option<Vec<String>>,
+
+    /// Column which scopes all rows to a tenant, all generated functions of tables with this column take the tenant as a parameter (for example: "tenant_id")
+    #[arg(long = "tenant-column")]
+    pub tenant_column: Option<String>,
+
     /// rust type which describes a connection
     ///
     /// For example:
@@ -78,38 +106,42 @@ pub struct MainOptions {
     #[arg(long = "no-serde")]
     pub no_serde: bool,
 
-    /// Set custom schema use path
-    #[arg(long = "schema-path", default_value = dsync::DEFAULT_SCHEMA_PATH)]
-    pub schema_path: String,
+    /// Set custom schema use path [default: crate::schema::]
+    #[arg(long = "schema-path")]
+    pub schema_path: Option<String>,
 
-    /// Set custom model use path
-    #[arg(long = "model-path", default_value = dsync::DEFAULT_MODEL_PATH)]
-    pub model_path: String,
+    /// Set custom model use path [default: crate::models::]
+    #[arg(long = "model-path")]
+    pub model_path: Option<String>,
 
     /// Do not generate the CRUD (impl) functions for generated models
     #[arg(long = "no-crud")]
     pub no_crud: bool,
 
-    /// Set which string type to use for Create* structs
-    #[arg(long = "create-str", default_value = "string")]
-    pub create_str: StringTypeCli,
+    /// Set which string type to use for Create* structs [default: string]
+    #[arg(long = "create-str")]
+    pub create_str: Option<StringTypeCli>,
 
-    /// Set which string type to use for Update* structs
-    #[arg(long = "update-str", default_value = "string")]
-    pub update_str: StringTypeCli,
+    /// Set which string type to use for Update* structs [default: string]
+    #[arg(long = "update-str")]
+    pub update_str: Option<StringTypeCli>,
 
-    /// Set which bytes type to use for Create* structs
-    #[arg(long = "create-bytes", default_value = "vec")]
-    pub create_bytes: BytesTypeCli,
+    /// Set which bytes type to use for Create* structs [default: vec]
+    #[arg(long = "create-bytes")]
+    pub create_bytes: Option<BytesTypeCli>,
 
-    /// Set which bytes type to use for Update* structs
-    #[arg(long = "update-bytes", default_value = "vec")]
-    pub update_bytes: BytesTypeCli,
+    /// Set which bytes type to use for Update* structs [default: vec]
+    #[arg(long = "update-bytes")]
+    pub update_bytes: Option<BytesTypeCli>,
 
     /// Only Generate a single model file instead of a directory with "mod.rs" and "generated.rs"
     #[arg(long = "single-model-file")]
     pub single_model_file: bool,
 
+    /// Generate a "upsert" function (insert, or update on primary key conflict) for all tables where possible
+    #[arg(long = "upsert")]
+    pub upsert: bool,
+
     /// Generate common structs only once in a "common.rs" file
     #[arg(long = "once-common-structs")]
     pub once_common_structs: bool,
@@ -126,6 +158,20 @@ pub struct MainOptions {
     #[arg(long = "readonly-suffix")]
     pub readonly_suffixes: Vec<String>,
 
+    /// Use a specific rust type for a diesel sql type, can be given multiple times
+    /// (example: "Numeric=rust_decimal::Decimal")
+    #[arg(long = "type-override", value_parser = parse_key_value)]
+    pub type_overrides: Vec<(String, String)>,
+
+    /// Set which rust type to use for postgres "Inet" & "Cidr" columns [default: ipnetwork]
+    #[arg(long = "network-address-type")]
+    pub network_address_type: Option<NetworkAddressTypeCli>,
+
+    /// Set how the generated "create" & "update" functions get the row back
+    /// [default: reread for mysql, returning otherwise]
+    #[arg(long = "returning-mode")]
+    pub returning_mode: Option<ReturningModeCli>,
+
     #[cfg(feature = "advanced-queries")]
     /// Set which diesel backend to use (something which implements `diesel::backend::Backend`)
     /// Diesel provides the following backends:
@@ -138,6 +184,16 @@ pub struct MainOptions {
     pub diesel_backend: String,
 }
 
+/// Parse a "KEY=VALUE" argument
+fn parse_key_value(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((key, value)) if !key.is_empty() && !value.is_empty() => {
+            Ok((key.to_string(), value.to_string()))
+        }
+        _ => Err(format!("expected \"KEY=VALUE\", got \"{value}\"")),
+    }
+}
+
 #[derive(Debug, ValueEnum, Clone, PartialEq, Default)]
 pub enum StringTypeCli {
     /// Use "String"
@@ -159,6 +215,44 @@ impl From<StringTypeCli> for StringType {
     }
 }
 
+#[derive(Debug, ValueEnum, Clone, PartialEq, Default)]
+pub enum NetworkAddressTypeCli {
+    /// Use "ipnetwork::IpNetwork" (diesel feature "network-address")
+    #[default]
+    Ipnetwork,
+    /// Use "ipnet::IpNet" (diesel feature "ipnet-address")
+    Ipnet,
+}
+
+impl From<NetworkAddressTypeCli> for NetworkAddressType {
+    fn from(value: NetworkAddressTypeCli) -> Self {
+        match value {
+            NetworkAddressTypeCli::Ipnetwork => NetworkAddressType::IpNetwork,
+            NetworkAddressTypeCli::Ipnet => NetworkAddressType::IpNet,
+        }
+    }
+}
+
+#[derive(Debug, ValueEnum, Clone, PartialEq)]
+pub enum ReturningModeCli {
+    /// Use a "RETURNING" clause (postgres, sqlite with diesel feature "returning_clauses_for_sqlite_3_35")
+    Returning,
+    /// Use "execute" and read the row again by its primary key(s) or "LAST_INSERT_ID()" (mysql)
+    Reread,
+    /// Only use "execute" and return the number of affected rows
+    Execute,
+}
+
+impl From<ReturningModeCli> for ReturningMode {
+    fn from(value: ReturningModeCli) -> Self {
+        match value {
+            ReturningModeCli::Returning => ReturningMode::Returning,
+            ReturningModeCli::Reread => ReturningMode::Reread,
+            ReturningModeCli::Execute => ReturningMode::Execute,
+        }
+    }
+}
+
 #[derive(Debug, ValueEnum, Clone, PartialEq, Default)]
 pub enum BytesTypeCli {
     /// Use "Vec<u8>"
@@ -184,7 +278,7 @@ fn main() {
     let res = actual_main();
 
     if let Err(err) = res {
-        eprintln!("Error:\n{err}");
+        eprintln!("{}", err.render());
         #[cfg(feature = "backtrace")]
         {
             let backtrace = err.backtrace().to_string();
@@ -219,13 +313,44 @@ fn actual_main() -> dsync::Result<()> {
         .args
         .expect("cli.args should be defined if no subcommand is given");
 
-    let cols = args.autogenerated_columns.unwrap_or_default();
-    let mut default_table_options = TableOptions::default()
-        .autogenerated_columns(cols.iter().map(|t| t.as_str()).collect::<Vec<&str>>())
-        .create_str_type(args.create_str.into())
-        .update_str_type(args.update_str.into())
-        .create_bytes_type(args.create_bytes.into())
-        .update_bytes_type(args.update_bytes.into());
+    let config_file = match &args.config {
+        Some(path) => ConfigFile::from_path(path)?,
+        None => ConfigFile::default(),
+    };
+    let mut options = config_file.to_generation_config_opts();
+
+    // options given on the command-line override the options from the config file
+    let mut default_table_options = options.default_table_options;
+
+    if let Some(cols) = &args.autogenerated_columns {
+        default_table_options = default_table_options
+            .autogenerated_columns(cols.iter().map(|t| t.as_str()).collect::<Vec<&str>>());
+    }
+
+    if let Some(cols) = &args.immutable_columns {
+        default_table_options = default_table_options
+            .immutable_columns(cols.iter().map(|t| t.as_str()).collect::<Vec<&str>>());
+    }
+
+    if let Some(col) = &args.tenant_column {
+        default_table_options = default_table_options.tenant_column(col);
+    }
+
+    if let Some(create_str) = args.create_str {
+        default_table_options = default_table_options.create_str_type(create_str.into());
+    }
+
+    if let Some(update_str) = args.update_str {
+        default_table_options = default_table_options.update_str_type(update_str.into());
+    }
+
+    if let Some(create_bytes) = args.create_bytes {
+        default_table_options = default_table_options.create_bytes_type(create_bytes.into());
+    }
+
+    if let Some(update_bytes) = args.update_bytes {
+        default_table_options = default_table_options.update_bytes_type(update_bytes.into());
+    }
 
     #[cfg(feature = "tsync")]
     if args.tsync {
@@ -249,25 +374,63 @@ fn actual_main() -> dsync::Result<()> {
         default_table_options = default_table_options.single_model_file();
     }
 
-    let changes = dsync::generate_files(
-        &args.input,
-        &args.output,
-        GenerationConfig {
-            connection_type: args.connection_type,
-            #[cfg(feature = "advanced-queries")]
-            diesel_backend: args.diesel_backend,
-            options: GenerationConfigOpts {
-                default_table_options,
-                table_options: HashMap::from([]),
-                schema_path: args.schema_path,
-                model_path: args.model_path,
-                once_common_structs: args.once_common_structs,
-                once_connection_type: args.once_connection_type,
-                readonly_prefixes: args.readonly_prefixes,
-                readonly_suffixes: args.readonly_suffixes,
-            },
-        },
-    )?;
+    if args.upsert {
+        default_table_options = default_table_options.upsert();
+    }
+
+    options.default_table_options = default_table_options;
+
+    if let Some(schema_path) = args.schema_path {
+        options.schema_path = schema_path;
+    }
+
+    if let Some(model_path) = args.model_path {
+        options.model_path = model_path;
+    }
+
+    if let Some(network_address_type) = args.network_address_type {
+        options.network_address_type = network_address_type.into();
+    }
+
+    if let Some(returning_mode) = args.returning_mode {
+        options.returning_mode = Some(returning_mode.into());
+    }
+
+    options.once_common_structs |= args.once_common_structs;
+    options.once_connection_type |= args.once_connection_type;
+    options.readonly_prefixes.extend(args.readonly_prefixes);
+    options.readonly_suffixes.extend(args.readonly_suffixes);
+
+    for (name, ty) in args.type_overrides.iter() {
+        // sql type names are case-insensitive, so make sure the command-line always wins over the config file
+        options
+            .type_overrides
+            .retain(|key, _| !key.eq_ignore_ascii_case(name));
+        options.type_overrides.insert(name, ty);
+    }
+
+    let config = GenerationConfig {
+        connection_type: args.connection_type,
+        #[cfg(feature = "advanced-queries")]
+        diesel_backend: args.diesel_backend,
+        options,
+    };
+
+    if args.check || args.diff {
+        let changes = dsync::check_files(&args.input, &args.output, config)?;
+
+        if args.diff {
+            command_diff(&changes);
+        }
+
+        if args.check {
+            return command_check(&changes);
+        }
+
+        return Ok(());
+    }
+
+    let changes = dsync::generate_files(&args.input, &args.output, config)?;
 
     let mut modified: usize = 0;
 
@@ -283,6 +446,41 @@ fn actual_main() -> dsync::Result<()> {
     Ok(())
 }
 
+/// Handler function for the "--check" option
+///
+/// Exits the process with code `1` if any file is not up-to-date
+fn command_check(changes: &[FileChange]) -> dsync::Result<()> {
+    let mut outdated: usize = 0;
+
+    for change in changes {
+        if change.status != FileChangeStatus::Unchanged {
+            println!("{} {}", change.status, change.file.to_string_lossy());
+            outdated += 1;
+        }
+    }
+
+    if outdated > 0 {
+        eprintln!(
+            "{} files are not up-to-date, run dsync without \"--check\" to update them",
+            outdated
+        );
+        std::process::exit(1);
+    }
+
+    println!("All files are up-to-date");
+
+    Ok(())
+}
+
+/// Handler function for the "--diff" option
+fn command_diff(changes: &[FileChange]) {
+    for change in changes {
+        if change.status != FileChangeStatus::Unchanged {
+            print!("{}", change.unified_diff());
+        }
+    }
+}
+
 /// Handler function for the "completions" subcommand
 /// This function is mainly to keep the code structured and sorted
 #[inline]
@@ -1,9 +1,10 @@
-use heck::ToPascalCase;
+use heck::{ToPascalCase, ToSnakeCase};
 use indoc::formatdoc;
 use std::borrow::Cow;
 
-use crate::parser::{ParsedColumnMacro, ParsedTableMacro, FILE_SIGNATURE};
-use crate::{get_table_module_name, GenerationConfig, TableOptions};
+use crate::error::{Error, ErrorEnum, Result};
+use crate::parser::{ParsedColumnMacro, ParsedSqlType, ParsedTableMacro, FILE_SIGNATURE};
+use crate::{get_table_module_name, GenerationConfig, ReturningMode, TableOptions};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum StructType {
@@ -76,6 +77,8 @@ pub struct StructField {
     pub name: String,
     /// Actual table column name
     pub column_name: String,
+    /// Doc comment lines from the schema, without the leading `///`
+    pub doc_comments: Vec<String>,
     /// Base Rust type, like "String" or "i32" or "u32"
     pub base_type: String,
     /// Indicate that this field is optional
@@ -109,19 +112,13 @@ impl From<&ParsedColumnMacro> for StructField {
     fn from(value: &ParsedColumnMacro) -> Self {
         let name = value.name.to_string();
 
-        // convert integers to proper rust integers
-        let base_type = if value.is_unsigned {
-            value.ty.replace('i', "u")
-        } else {
-            value.ty.clone()
-        };
-
         Self {
             name,
-            base_type,
+            base_type: value.ty.clone(),
             is_optional: value.is_nullable,
             is_vec: value.is_array,
             column_name: value.column_name.clone(),
+            doc_comments: value.doc_comments.clone(),
         }
     }
 }
@@ -142,6 +139,10 @@ pub mod derives {
     #[cfg(feature = "derive-queryablebyname")]
     pub const QUERYABLEBYNAME: &str = "diesel::QueryableByName";
     pub const PARTIALEQ: &str = "PartialEq";
+    pub const EQ: &str = "Eq";
+    pub const COPY: &str = "Copy";
+    pub const ASEXPRESSION: &str = "diesel::expression::AsExpression";
+    pub const FROMSQLROW: &str = "diesel::deserialize::FromSqlRow";
 }
 
 impl<'a> Struct<'a> {
@@ -250,15 +251,37 @@ impl<'a> Struct<'a> {
                     .opts
                     .get_autogenerated_columns()
                     .contains(&c.name.to_string().as_str());
+                let is_tenant = self.opts.get_tenant_column() == Some(c.name.to_string().as_str());
 
                 match self.ty {
                     StructType::Read => true,
                     StructType::Update => {
                         let is_pk = self.table.primary_key_columns.contains(&c.name);
+                        // the soft-delete column is only managed by "delete" & "restore"
+                        let is_soft_delete =
+                            self.opts.get_soft_delete_column() == Some(c.name.to_string().as_str());
+                        // touched columns are always set by "update" itself
+                        let is_touched = self
+                            .opts
+                            .get_touch_on_update()
+                            .contains(&c.name.to_string().as_str());
+                        let is_immutable = self
+                            .opts
+                            .get_immutable_columns()
+                            .contains(&c.name.to_string().as_str());
+                        // the version is only incremented by "update" itself
+                        let is_version =
+                            self.opts.get_version_column() == Some(c.name.to_string().as_str());
 
                         !is_pk
+                            && !is_soft_delete
+                            && !is_touched
+                            && !is_immutable
+                            && !is_version
+                            && !is_tenant
                     }
-                    StructType::Create => !is_autogenerated,
+                    // the tenant is always set from the tenant parameter of "create"
+                    StructType::Create => !is_autogenerated && !is_tenant,
                 }
             })
             .map(StructField::from)
@@ -285,8 +308,8 @@ impl<'a> Struct<'a> {
         let primary_keys: Vec<String> = table.primary_key_column_names();
 
         let belongs_to = table
-            .foreign_keys
-            .iter()
+            .primary_associations()
+            .into_iter()
             .map(|fk| {
                 format!(
                     ", belongs_to({foreign_table_name}, foreign_key={join_column})",
@@ -356,14 +379,18 @@ impl<'a> Struct<'a> {
                 field_type = format!("Option<{}>", field_type).into();
             }
 
-            lines.push(format!(
-                "    /// Field representing column `{column_name}`",
-                column_name = f.column_name
-            ));
+            if f.doc_comments.is_empty() {
+                lines.push(format!(
+                    "    /// Field representing column `{column_name}`",
+                    column_name = f.column_name
+                ));
+            } else {
+                lines.extend(f.doc_comments.iter().map(|l| format!("    ///{l}")));
+            }
             lines.push(format!(r#"    pub {field_name}: {field_type},"#));
         }
 
-        let doccomment = match ty {
+        let mut doccomment = match ty {
             StructType::Read => format!(
                 "/// Struct representing a row in table `{table_name}`",
                 table_name = table.name
@@ -380,6 +407,14 @@ impl<'a> Struct<'a> {
             ),
         };
 
+        // append the table's doc comments from the schema, if any
+        if !table.doc_comments.is_empty() {
+            doccomment.push_str("\n///");
+            for line in table.doc_comments.iter() {
+                doccomment.push_str(&format!("\n///{line}"));
+            }
+        }
+
         let struct_code = formatdoc!(
             r#"
             {doccomment}
@@ -433,7 +468,7 @@ fn build_table_fns(
     config: &GenerationConfig,
     create_struct: Struct,
     update_struct: Struct,
-) -> String {
+) -> Result<String> {
     let table_options = config.table(&table.name.to_string());
 
     let primary_column_name_and_type: Vec<(String, String)> = table
@@ -450,7 +485,31 @@ fn build_table_fns(
         })
         .collect();
 
-    let item_id_params = primary_column_name_and_type
+    let tenant_column = get_tenant_column(table, config, &table_options)?;
+    // parameters & filters for all functions which work on rows of a single tenant
+    let tenant_param = tenant_column
+        .map(|column| format!(", param_{name}: {ty}", name = column.name, ty = column.ty))
+        .unwrap_or_default();
+    #[cfg(feature = "advanced-queries")]
+    let tenant_arg = tenant_column
+        .map(|column| format!("&param_{}, ", column.name))
+        .unwrap_or_default();
+    #[cfg(feature = "advanced-queries")]
+    let tenant_value_arg = tenant_column
+        .map(|column| format!("param_{}, ", column.name))
+        .unwrap_or_default();
+
+    // the rows are identified by the primary key(s) and the tenant (if not already part of the primary key)
+    let mut scoped_column_name_and_type = primary_column_name_and_type.clone();
+    if let Some(column) = tenant_column {
+        let name = column.name.to_string();
+
+        if !scoped_column_name_and_type.iter().any(|(n, _)| n == &name) {
+            scoped_column_name_and_type.insert(0, (name, column.ty.clone()));
+        }
+    }
+
+    let item_id_params = scoped_column_name_and_type
         .iter()
         .map(|name_and_type| {
             format!(
@@ -461,7 +520,7 @@ fn build_table_fns(
         })
         .collect::<Vec<String>>()
         .join(", ");
-    let item_id_filters = primary_column_name_and_type
+    let item_id_filters = scoped_column_name_and_type
         .iter()
         .map(|name_and_type| {
             format!(
@@ -471,16 +530,23 @@ fn build_table_fns(
         })
         .collect::<Vec<String>>()
         .join(".");
+    // same as "item_id_filters", but not moving the parameters, so they can be used again
+    let item_id_filters_ref = scoped_column_name_and_type
+        .iter()
+        .map(|name_and_type| format!("filter({name}.eq(&param_{name}))", name = name_and_type.0))
+        .collect::<Vec<String>>()
+        .join(".");
 
     // template variables
     let table_name = table.name.to_string();
     let (async_keyword, await_keyword) = get_async(&table_options);
 
     let struct_name = &table.struct_name;
-    let schema_path = config.get_schema_path();
+    let schema_path = &table.schema_path(config);
     let create_struct_identifier = &create_struct.identifier;
     let update_struct_identifier = &update_struct.identifier;
     let is_readonly = table_options.get_readonly();
+    let returning_mode = config.get_returning_mode();
 
     let mut buffer = String::new();
 
@@ -492,28 +558,135 @@ fn build_table_fns(
     buffer.push_str(&format!("impl {struct_name} {{"));
 
     if !is_readonly {
+        // filter to read a inserted row again, if the backend does not support "RETURNING"
+        let create_reread_filter = match returning_mode {
+            ReturningMode::Reread => {
+                Some(build_create_reread_filter(table, config, &table_options)?)
+            }
+            _ => None,
+        };
+
+        let (create_doc, create_values) = match (create_struct.has_fields(), tenant_column) {
+            (true, None) => (
+                format!("Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`]"),
+                "values(item)".to_string(),
+            ),
+            (true, Some(tenant_column)) => (
+                format!("Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`] for the given tenant"),
+                format!("values((item, {name}.eq(&param_{name})))", name = tenant_column.name),
+            ),
+            (false, None) => (
+                format!("Insert a new row into `{table_name}` with all default values"),
+                "default_values()".to_string(),
+            ),
+            (false, Some(tenant_column)) => (
+                format!("Insert a new row into `{table_name}` with all default values for the given tenant"),
+                format!("values({name}.eq(&param_{name}))", name = tenant_column.name),
+            ),
+        };
+        let create_params = if create_struct.has_fields() {
+            format!("{tenant_param}, item: &{create_struct_identifier}")
+        } else {
+            tenant_param.clone()
+        };
+
+        match (returning_mode, create_reread_filter) {
+            (ReturningMode::Returning, _) => {
+                buffer.push_str(&format!(
+                    r##"
+    /// {create_doc}
+    pub{async_keyword} fn create(db: &mut ConnectionType{create_params}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::insert_into({table_name}).{create_values}.get_result::<Self>(db){await_keyword}
+    }}
+"##
+                ));
+            }
+            (ReturningMode::Reread, Some(reread_filter)) => {
+                buffer.push_str(&format!(
+                    r##"
+    /// {create_doc}
+    ///
+    /// The row is read again after inserting, because the backend does not support `RETURNING`
+    pub{async_keyword} fn create(db: &mut ConnectionType{create_params}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::insert_into({table_name}).{create_values}.execute(db){await_keyword}?;
+        {table_name}.{reread_filter}.first::<Self>(db){await_keyword}
+    }}
+"##
+                ));
+            }
+            // "Execute", or the row cannot be identified again after inserting
+            _ => {
+                buffer.push_str(&format!(
+                    r##"
+    /// {create_doc}, returning the number of inserted rows
+    pub{async_keyword} fn create(db: &mut ConnectionType{create_params}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::insert_into({table_name}).{create_values}.execute(db){await_keyword}
+    }}
+"##
+                ));
+            }
+        }
+
         if create_struct.has_fields() {
-            buffer.push_str(&format!(
-            r##"
-    /// Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`]
-    pub{async_keyword} fn create(db: &mut ConnectionType, item: &{create_struct_identifier}) -> diesel::QueryResult<Self> {{
+            let create_many_values = match tenant_column {
+                Some(tenant_column) => format!(
+                    "values(items.iter().map(|item| (item, {name}.eq(&param_{name}))).collect::<Vec<_>>())",
+                    name = tenant_column.name
+                ),
+                None => "values(items)".to_string(),
+            };
+
+            // sqlite batch inserts are executed row by row, which does not support "RETURNING"
+            if returning_mode == ReturningMode::Returning && !config.is_sqlite() {
+                buffer.push_str(&format!(
+                    r##"
+    /// Insert multiple new rows into `{table_name}` with the given [`{create_struct_identifier}`]s
+    pub{async_keyword} fn create_many(db: &mut ConnectionType{tenant_param}, items: &[{create_struct_identifier}]) -> diesel::QueryResult<Vec<Self>> {{
         use {schema_path}{table_name}::dsl::*;
 
-        diesel::insert_into({table_name}).values(item).get_result::<Self>(db){await_keyword}
+        diesel::insert_into({table_name}).{create_many_values}.get_results::<Self>(db){await_keyword}
     }}
 "##
-        ));
-        } else {
-            buffer.push_str(&format!(
-                r##"
-    /// Insert a new row into `{table_name}` with all default values
-    pub{async_keyword} fn create(db: &mut ConnectionType) -> diesel::QueryResult<Self> {{
+                ));
+            } else {
+                // the rows of a batch insert cannot be reliably read again without "RETURNING"
+                buffer.push_str(&format!(
+                    r##"
+    /// Insert multiple new rows into `{table_name}` with the given [`{create_struct_identifier}`]s, returning the number of inserted rows
+    pub{async_keyword} fn create_many(db: &mut ConnectionType{tenant_param}, items: &[{create_struct_identifier}]) -> diesel::QueryResult<usize> {{
         use {schema_path}{table_name}::dsl::*;
 
-        diesel::insert_into({table_name}).default_values().get_result::<Self>(db){await_keyword}
+        diesel::insert_into({table_name}).{create_many_values}.execute(db){await_keyword}
     }}
 "##
-            ));
+                ));
+            }
+        }
+
+        if table_options.get_upsert() {
+            let upsert_fn = build_upsert_fn(
+                table,
+                config,
+                &table_options,
+                &create_struct,
+                &update_struct,
+            );
+            let explicit_upsert = config
+                .get_explicit_table_options(&table.name.to_string())
+                .is_some_and(|v| v.upsert.is_some());
+
+            match upsert_fn {
+                Ok(upsert_fn) => buffer.push_str(&upsert_fn),
+                // tables which only inherit "upsert" (like from "--upsert") are skipped if it cannot be generated for them
+                Err(_) if !explicit_upsert => (),
+                Err(err) => return Err(err),
+            }
         }
     }
 
@@ -524,8 +697,31 @@ fn build_table_fns(
         "keys"
     };
 
-    buffer.push_str(&format!(
-        r##"
+    let soft_delete_column = get_soft_delete_column(table, &table_options)?;
+
+    if let Some(soft_delete_column) = soft_delete_column {
+        let soft_delete_column_name = soft_delete_column.name.to_string();
+
+        buffer.push_str(&format!(
+            r##"
+    /// Get a row from `{table_name}`, identified by the primary {key_maybe_multiple}, excluding soft-deleted rows
+    pub{async_keyword} fn read(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {table_name}.{item_id_filters}.filter({soft_delete_column_name}.is_null()).first::<Self>(db){await_keyword}
+    }}
+
+    /// Get a row from `{table_name}`, identified by the primary {key_maybe_multiple}, including soft-deleted rows
+    pub{async_keyword} fn read_with_deleted(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {table_name}.{item_id_filters}.first::<Self>(db){await_keyword}
+    }}
+"##
+        ));
+    } else {
+        buffer.push_str(&format!(
+            r##"
     /// Get a row from `{table_name}`, identified by the primary {key_maybe_multiple}
     pub{async_keyword} fn read(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<Self> {{
         use {schema_path}{table_name}::dsl::*;
@@ -533,16 +729,26 @@ fn build_table_fns(
         {table_name}.{item_id_filters}.first::<Self>(db){await_keyword}
     }}
 "##
-    ));
+        ));
+    }
+
+    buffer.push_str(&build_relation_loaders(table, config, &table_options)?);
 
     #[cfg(feature = "advanced-queries")]
-    buffer.push_str(&format!(r##"
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
-    pub{async_keyword} fn paginate(db: &mut ConnectionType, page: i64, page_size: i64, filter: {struct_name}Filter) -> diesel::QueryResult<PaginationResult<Self>> {{
+    // with a soft-delete column, "paginate" excludes deleted rows and "paginate_with_deleted" includes them
+    for (fn_suffix, doc_suffix) in soft_delete_variants(soft_delete_column) {
+        buffer.push_str(&format!(r##"
+    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page){doc_suffix}
+    pub{async_keyword} fn paginate{fn_suffix}(db: &mut ConnectionType{tenant_param}, page: i64, page_size: i64, filter: {struct_name}Filter) -> diesel::QueryResult<PaginationResult<Self>> {{
+        Self::paginate{fn_suffix}_ordered(db, {tenant_value_arg}page, page_size, filter, {struct_name}OrderBy::default()){await_keyword}
+    }}
+
+    /// Same as [`Self::paginate{fn_suffix}`], but the pages are ordered by `order_by`
+    pub{async_keyword} fn paginate{fn_suffix}_ordered(db: &mut ConnectionType{tenant_param}, page: i64, page_size: i64, filter: {struct_name}Filter, order_by: {struct_name}OrderBy) -> diesel::QueryResult<PaginationResult<Self>> {{
         let page = page.max(0);
         let page_size = page_size.max(1);
-        let total_items = Self::filter(filter.clone()).count().get_result(db){await_keyword}?;
-        let items = Self::filter(filter).limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
+        let total_items = Self::filter{fn_suffix}({tenant_arg}filter.clone()).count().get_result(db){await_keyword}?;
+        let items = Self::filter{fn_suffix}_ordered({tenant_arg}filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
 
         Ok(PaginationResult {{
             items,
@@ -554,6 +760,76 @@ fn build_table_fns(
         }})
     }}
 "##));
+    }
+
+    #[cfg(feature = "advanced-queries")]
+    if let Some(cursor_columns) = get_cursor_columns(table, &table_options)? {
+        let cursor_column_names: Vec<String> =
+            cursor_columns.iter().map(|c| c.name.to_string()).collect();
+        let cursor_columns_doc = cursor_column_names
+            .iter()
+            .map(|c| format!("`{c}`"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let cursor_order = match cursor_column_names.as_slice() {
+            [single] => format!("{schema_path}{table_name}::{single}.asc()"),
+            _ => format!(
+                "({})",
+                cursor_column_names
+                    .iter()
+                    .map(|c| format!("{schema_path}{table_name}::{c}.asc()"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        };
+        // rows after the cursor in the order of all cursor columns, like "a > ca OR (a = ca AND b > cb)"
+        let cursor_filter =
+            cursor_column_names
+                .iter()
+                .rev()
+                .fold(String::new(), |after_rest, c| {
+                    let column = format!("{schema_path}{table_name}::{c}");
+                    if after_rest.is_empty() {
+                        format!("{column}.gt(&cursor.{c})")
+                    } else {
+                        format!(
+                        "{column}.gt(&cursor.{c}).or({column}.eq(&cursor.{c}).and({after_rest}))"
+                    )
+                    }
+                });
+        let next_cursor_fields = cursor_column_names
+            .iter()
+            .map(|c| format!("{c}: item.{c}.clone()"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        buffer.push_str(&format!(r##"
+    /// Paginates through the table ordered by {cursor_columns_doc}, starting after the given cursor (`None` for the first page)
+    ///
+    /// Unlike `paginate`, this does not count all rows and does not use a offset, which makes it suitable for large tables
+    pub{async_keyword} fn paginate_after(db: &mut ConnectionType{tenant_param}, cursor: Option<{struct_name}Cursor>, page_size: i64, filter: {struct_name}Filter) -> diesel::QueryResult<CursorPaginationResult<Self, {struct_name}Cursor>> {{
+        let page_size = page_size.max(1);
+        let mut query = Self::filter({tenant_arg}filter);
+
+        if let Some(cursor) = &cursor {{
+            query = query.filter({cursor_filter});
+        }}
+
+        let items = query.order({cursor_order}).limit(page_size).load::<Self>(db){await_keyword}?;
+        let next_cursor = if items.len() as i64 == page_size {{
+            items.last().map(|item| {struct_name}Cursor {{ {next_cursor_fields} }})
+        }} else {{
+            None
+        }};
+
+        Ok(CursorPaginationResult {{
+            items,
+            next_cursor,
+            page_size,
+        }})
+    }}
+"##));
+    }
 
     #[cfg(feature = "advanced-queries")]
     // Table::filter() helper fn
@@ -565,7 +841,7 @@ fn build_table_fns(
             .map(|column| {
                 let column_name = column.name.to_string();
 
-                if column.is_nullable {
+                let mut eq_filter = if column.is_nullable {
                     // "Option::None" will never match anything, and "is_null" is required to be used, see https://docs.diesel.rs/master/diesel/expression_methods/trait.ExpressionMethods.html#method.eq
                     format!(
                         r##"
@@ -584,10 +860,55 @@ fn build_table_fns(
             query = query.filter({schema_path}{table_name}::{column_name}.eq(filter_{column_name}));
         }}"##
                     )
+                };
+
+                for operator in filter_operators(column, config) {
+                    let FilterOperator { suffix, method, .. } = operator;
+                    let field_name = filter_field_name(&column_name, suffix);
+                    eq_filter.push_str(&format!(
+                        r##"
+        if let Some(filter_{field_name}) = filter.{field_name} {{
+            query = query.filter({schema_path}{table_name}::{column_name}.{method}(filter_{field_name}));
+        }}"##
+                    ));
                 }
+
+                if column.is_nullable {
+                    let field_name = filter_field_name(&column_name, "_is_not_null");
+                    eq_filter.push_str(&format!(
+                        r##"
+        if filter.{field_name} {{
+            query = query.filter({schema_path}{table_name}::{column_name}.is_not_null());
+        }}"##
+                    ));
+                }
+
+                eq_filter
             })
             .collect::<Vec<_>>()
             .join("");
+        // with a soft-delete column, the full filter is generated as "filter_with_deleted" and "filter" excludes deleted rows
+        let filter_fn_suffix = if soft_delete_column.is_some() {
+            "_with_deleted"
+        } else {
+            ""
+        };
+        // the tenant is borrowed for the lifetime of the boxed query, so it can be used for multiple queries (like in "paginate")
+        let (filter_tenant_param, filter_tenant_arg, filter_tenant_filter) = match tenant_column {
+            Some(tenant_column) => (
+                format!(
+                    "param_{name}: &'a {ty},\n        ",
+                    name = tenant_column.name,
+                    ty = tenant_column.ty
+                ),
+                format!("param_{}, ", tenant_column.name),
+                format!(
+                    "query = query.filter({schema_path}{table_name}::{name}.eq(param_{name}));\n",
+                    name = tenant_column.name
+                ),
+            ),
+            None => (String::new(), String::new(), String::new()),
+        };
         buffer.push_str(&format!(
             r##"
     /// A utility function to help build custom search queries
@@ -604,16 +925,47 @@ fn build_table_fns(
     /// // delete completed todos
     /// diesel::delete(query).execute(db)?;
     /// ```
-    pub fn filter<'a>(
-        filter: {struct_name}Filter,
+    ///
+    /// The query is not ordered, use [`Self::filter{filter_fn_suffix}_ordered`] or [`{struct_name}OrderBy::apply`] to order it
+    pub fn filter{filter_fn_suffix}<'a>(
+        {filter_tenant_param}filter: {struct_name}Filter,
     ) -> {schema_path}{table_name}::BoxedQuery<'a, {diesel_backend}> {{
         let mut query = {schema_path}{table_name}::table.into_boxed();
-        {filters}
+        {filter_tenant_filter}{filters}
         
         query
     }}
 "##
         ));
+
+        if let Some(soft_delete_column) = soft_delete_column {
+            let soft_delete_column_name = soft_delete_column.name.to_string();
+
+            buffer.push_str(&format!(
+                r##"
+    /// Same as [`Self::filter_with_deleted`], but excluding soft-deleted rows
+    pub fn filter<'a>(
+        {filter_tenant_param}filter: {struct_name}Filter,
+    ) -> {schema_path}{table_name}::BoxedQuery<'a, {diesel_backend}> {{
+        Self::filter_with_deleted({filter_tenant_arg}filter).filter({schema_path}{table_name}::{soft_delete_column_name}.is_null())
+    }}
+"##
+            ));
+        }
+
+        for (fn_suffix, _) in soft_delete_variants(soft_delete_column) {
+            buffer.push_str(&format!(
+                r##"
+    /// Same as [`Self::filter{fn_suffix}`], but ordered by `order_by`
+    pub fn filter{fn_suffix}_ordered<'a>(
+        {filter_tenant_param}filter: {struct_name}Filter,
+        order_by: {struct_name}OrderBy,
+    ) -> {schema_path}{table_name}::BoxedQuery<'a, {diesel_backend}> {{
+        order_by.apply(Self::filter{fn_suffix}({filter_tenant_arg}filter))
+    }}
+"##
+            ));
+        }
     }
 
     // TODO: If primary key columns are attached to the form struct (not optionally)
@@ -625,19 +977,134 @@ fn build_table_fns(
         // In this scenario, we also have to check whether there are any updatable columns for which
         // we should generate an update() method.
 
-        buffer.push_str(&format!(r##"
+        let managed_assignments = build_managed_assignments(table, &table_options)?;
+        let update_set = if managed_assignments.is_empty() {
+            "item".to_string()
+        } else {
+            format!("(item, {})", managed_assignments.join(", "))
+        };
+
+        if let Some(version_column) = get_version_column(table, &table_options)? {
+            let version_column_name = version_column.name.to_string();
+            let version_column_type = &version_column.ty;
+            let version_filter = format!("filter({version_column_name}.eq(expected_version))");
+
+            match returning_mode {
+                ReturningMode::Returning => {
+                    buffer.push_str(&format!(r##"
+    /// Update a row in `{table_name}`, identified by the primary {key_maybe_multiple} and the expected `{version_column_name}` with [`{update_struct_identifier}`], incrementing `{version_column_name}`
+    ///
+    /// Returns `None` if the row does not exist or was changed concurrently (stale version)
+    pub{async_keyword} fn update(db: &mut ConnectionType, {item_id_params}, expected_version: {version_column_type}, item: &{update_struct_identifier}) -> diesel::QueryResult<Option<Self>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::update({table_name}.{item_id_filters}.{version_filter}).set({update_set}).get_result(db){await_keyword}.optional()
+    }}
+"##));
+                }
+                ReturningMode::Reread => {
+                    buffer.push_str(&format!(r##"
+    /// Update a row in `{table_name}`, identified by the primary {key_maybe_multiple} and the expected `{version_column_name}` with [`{update_struct_identifier}`], incrementing `{version_column_name}`
+    ///
+    /// Returns `None` if the row does not exist or was changed concurrently (stale version).
+    /// The row is read again after updating, because the backend does not support `RETURNING`
+    pub{async_keyword} fn update(db: &mut ConnectionType, {item_id_params}, expected_version: {version_column_type}, item: &{update_struct_identifier}) -> diesel::QueryResult<Option<Self>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        let updated = diesel::update({table_name}.{item_id_filters_ref}.{version_filter}).set({update_set}).execute(db){await_keyword}?;
+
+        if updated == 0 {{
+            return Ok(None);
+        }}
+
+        {table_name}.{item_id_filters}.first::<Self>(db){await_keyword}.map(Some)
+    }}
+"##));
+                }
+                ReturningMode::Execute => {
+                    buffer.push_str(&format!(r##"
+    /// Update a row in `{table_name}`, identified by the primary {key_maybe_multiple} and the expected `{version_column_name}` with [`{update_struct_identifier}`], incrementing `{version_column_name}`
+    ///
+    /// Returns the number of updated rows, which is `0` if the row does not exist or was changed concurrently (stale version)
+    pub{async_keyword} fn update(db: &mut ConnectionType, {item_id_params}, expected_version: {version_column_type}, item: &{update_struct_identifier}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::update({table_name}.{item_id_filters}.{version_filter}).set({update_set}).execute(db){await_keyword}
+    }}
+"##));
+                }
+            }
+        } else {
+            match returning_mode {
+                ReturningMode::Returning => {
+                    buffer.push_str(&format!(r##"
+    /// Update a row in `{table_name}`, identified by the primary {key_maybe_multiple} with [`{update_struct_identifier}`]
+    pub{async_keyword} fn update(db: &mut ConnectionType, {item_id_params}, item: &{update_struct_identifier}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::update({table_name}.{item_id_filters}).set({update_set}).get_result(db){await_keyword}
+    }}
+"##));
+                }
+                ReturningMode::Reread => {
+                    buffer.push_str(&format!(r##"
     /// Update a row in `{table_name}`, identified by the primary {key_maybe_multiple} with [`{update_struct_identifier}`]
+    ///
+    /// The row is read again after updating, because the backend does not support `RETURNING`
     pub{async_keyword} fn update(db: &mut ConnectionType, {item_id_params}, item: &{update_struct_identifier}) -> diesel::QueryResult<Self> {{
         use {schema_path}{table_name}::dsl::*;
 
-        diesel::update({table_name}.{item_id_filters}).set(item).get_result(db){await_keyword}
+        diesel::update({table_name}.{item_id_filters_ref}).set({update_set}).execute(db){await_keyword}?;
+        {table_name}.{item_id_filters}.first::<Self>(db){await_keyword}
+    }}
+"##));
+                }
+                ReturningMode::Execute => {
+                    buffer.push_str(&format!(r##"
+    /// Update a row in `{table_name}`, identified by the primary {key_maybe_multiple} with [`{update_struct_identifier}`], returning the number of updated rows
+    pub{async_keyword} fn update(db: &mut ConnectionType, {item_id_params}, item: &{update_struct_identifier}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::update({table_name}.{item_id_filters}).set({update_set}).execute(db){await_keyword}
     }}
 "##));
+                }
+            }
+        }
     }
 
     if !is_readonly {
-        buffer.push_str(&format!(
-            r##"
+        if let Some(soft_delete_column) = soft_delete_column {
+            let soft_delete_column_name = soft_delete_column.name.to_string();
+            let soft_delete_column_type = &soft_delete_column.ty;
+
+            buffer.push_str(&format!(
+                r##"
+    /// Soft-delete a row in `{table_name}`, identified by the primary {key_maybe_multiple}, by setting `{soft_delete_column_name}` to the current time
+    pub{async_keyword} fn delete(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::update({table_name}.{item_id_filters}.filter({soft_delete_column_name}.is_null())).set({soft_delete_column_name}.eq(diesel::dsl::now)).execute(db){await_keyword}
+    }}
+
+    /// Restore a soft-deleted row in `{table_name}`, identified by the primary {key_maybe_multiple}
+    pub{async_keyword} fn restore(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::update({table_name}.{item_id_filters}).set({soft_delete_column_name}.eq(None::<{soft_delete_column_type}>)).execute(db){await_keyword}
+    }}
+
+    /// Permanently delete a row in `{table_name}`, identified by the primary {key_maybe_multiple}
+    pub{async_keyword} fn hard_delete(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        diesel::delete({table_name}.{item_id_filters}).execute(db){await_keyword}
+    }}
+"##
+            ));
+        } else {
+            buffer.push_str(&format!(
+                r##"
     /// Delete a row in `{table_name}`, identified by the primary {key_maybe_multiple}
     pub{async_keyword} fn delete(db: &mut ConnectionType, {item_id_params}) -> diesel::QueryResult<usize> {{
         use {schema_path}{table_name}::dsl::*;
@@ -645,7 +1112,8 @@ fn build_table_fns(
         diesel::delete({table_name}.{item_id_filters}).execute(db){await_keyword}
     }}
 "##
-        ));
+            ));
+        }
     }
 
     buffer.push_str("}\n");
@@ -656,13 +1124,34 @@ fn build_table_fns(
         let filter_fields = table
             .columns
             .iter()
-            .map(|column| {
+            .flat_map(|column| {
                 let struct_field = StructField::from(column);
-                format!(
+                let mut fields = vec![format!(
                     "pub {column_name}: Option<{column_type}>,",
                     column_name = struct_field.name,
                     column_type = struct_field.to_rust_type()
-                )
+                )];
+
+                fields.extend(
+                    filter_operators(column, config)
+                        .into_iter()
+                        .map(|operator| {
+                            format!(
+                                "pub {field_name}: Option<{ty}>,",
+                                field_name = filter_field_name(&struct_field.name, operator.suffix),
+                                ty = operator.ty
+                            )
+                        }),
+                );
+
+                if column.is_nullable {
+                    fields.push(format!(
+                        "pub {}: bool,",
+                        filter_field_name(&struct_field.name, "_is_not_null")
+                    ));
+                }
+
+                fields
             })
             .collect::<Vec<_>>()
             .join("\n    ");
@@ -675,22 +1164,829 @@ fn build_table_fns(
     }}
     "##
         ));
+
+        if let Some(cursor_columns) = get_cursor_columns(table, &table_options)? {
+            let cursor_fields = cursor_columns
+                .iter()
+                .map(|column| {
+                    let struct_field = StructField::from(*column);
+                    format!(
+                        "pub {column_name}: {column_type},",
+                        column_name = struct_field.name,
+                        column_type = struct_field.to_rust_type()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n    ");
+            let serde_derive = if table_options.get_serde() {
+                format!(", {}, {}", derives::SERIALIZE, derives::DESERIALIZE)
+            } else {
+                String::new()
+            };
+
+            buffer.push_str(&formatdoc!(
+                r##"
+
+    /// Position after the last row of a page of [`{struct_name}::paginate_after`], to get the next page with
+    #[derive(Debug, Clone, PartialEq{serde_derive})]
+    pub struct {struct_name}Cursor {{
+        {cursor_fields}
+    }}
+    "##
+            ));
+        }
+
+        buffer.push('\n');
+        buffer.push_str(&build_order_by(table, config));
     }
 
-    buffer
+    Ok(buffer)
 }
 
-/// Generate common structs
-pub fn generate_common_structs(table_options: &TableOptions<'_>) -> String {
-    #[cfg(feature = "tsync")]
-    let tsync = match table_options.get_tsync() {
-        true => "#[tsync::tsync]\n",
-        false => "",
-    };
-    #[cfg(not(feature = "tsync"))]
-    let tsync = "";
+/// Generate the `{Struct}Column` enum and the `{Struct}OrderBy` struct, including their `FromStr` implementations
+#[cfg(feature = "advanced-queries")]
+fn build_order_by(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    let struct_name = &table.struct_name;
+    let table_name = table.name.to_string();
+    let schema_path = table.schema_path(config);
+    let diesel_backend = &config.diesel_backend;
 
-    formatdoc!(
+    // (column name, enum variant)
+    let columns: Vec<(String, String)> = table
+        .columns
+        .iter()
+        .map(|c| (c.name.to_string(), c.name.to_string().to_pascal_case()))
+        .collect();
+
+    let variants = columns
+        .iter()
+        .map(|(name, variant)| format!("    /// Column `{name}`\n    {variant},"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let from_str_arms = columns
+        .iter()
+        .map(|(name, variant)| format!("            \"{name}\" => Ok(Self::{variant}),"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let apply_arms = columns
+        .iter()
+        .map(|(name, variant)| {
+            format!(
+                "                ({struct_name}Column::{variant}, SortDirection::Asc) => query.then_order_by({schema_path}{table_name}::{name}.asc()),\n                ({struct_name}Column::{variant}, SortDirection::Desc) => query.then_order_by({schema_path}{table_name}::{name}.desc()),"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r##"/// All columns of `{table_name}`, for example to order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum {struct_name}Column {{
+{variants}
+}}
+
+impl std::str::FromStr for {struct_name}Column {{
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {{
+        match s {{
+{from_str_arms}
+            _ => Err(format!("unknown column \"{{s}}\" in table \"{table_name}\"")),
+        }}
+    }}
+}}
+
+/// Ordering for queries of `{table_name}`, by one or multiple columns (the first having the highest priority)
+///
+/// Can be parsed from a comma-separated list of columns, where a `-` prefix means descending order (like `-created_at,title`)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct {struct_name}OrderBy(pub Vec<({struct_name}Column, SortDirection)>);
+
+impl {struct_name}OrderBy {{
+    /// Apply the ordering to a query of `{table_name}`, like the one from [`{struct_name}::filter`]
+    pub fn apply<'a>(
+        &self,
+        mut query: {schema_path}{table_name}::BoxedQuery<'a, {diesel_backend}>,
+    ) -> {schema_path}{table_name}::BoxedQuery<'a, {diesel_backend}> {{
+        for (column, direction) in self.0.iter() {{
+            query = match (column, direction) {{
+{apply_arms}
+            }};
+        }}
+
+        query
+    }}
+}}
+
+impl std::str::FromStr for {struct_name}OrderBy {{
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {{
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| match v.strip_prefix('-') {{
+                Some(column) => Ok((column.parse()?, SortDirection::Desc)),
+                None => Ok((v.strip_prefix('+').unwrap_or(v).parse()?, SortDirection::Asc)),
+            }})
+            .collect::<std::result::Result<Vec<_>, Self::Err>>()
+            .map(Self)
+    }}
+}}
+"##
+    )
+}
+
+/// Get the name of a filter field for a column with a suffix (like `id_gt`)
+///
+/// A trailing underscore of the column (like `type_`) is removed, so the name stays snake case (`type_in` instead of `type__in`)
+#[cfg(feature = "advanced-queries")]
+fn filter_field_name(column_name: &str, suffix: &str) -> String {
+    format!("{}{suffix}", column_name.trim_end_matches('_'))
+}
+
+/// A additional filter operator for a column in the `{Struct}Filter` struct, besides `eq`
+#[cfg(feature = "advanced-queries")]
+struct FilterOperator {
+    /// Suffix of the filter field (like `_gt` for `id_gt`)
+    suffix: &'static str,
+    /// Diesel expression method to use (like `gt`)
+    method: &'static str,
+    /// Rust type of the filter value (without the `Option`)
+    ty: String,
+}
+
+/// Get all additional filter operators for a column, depending on its sql type
+///
+/// - all non-array & non-bool columns: `_in` (`eq_any`)
+/// - numeric & temporal columns: `_gt`, `_gte`, `_lt`, `_lte`
+/// - text columns: `_like` and `_ilike` (postgres only)
+#[cfg(feature = "advanced-queries")]
+fn filter_operators(column: &ParsedColumnMacro, config: &GenerationConfig) -> Vec<FilterOperator> {
+    let mut operators = vec![];
+
+    if column.is_array {
+        return operators;
+    }
+
+    let sql_type = column.sql_type.to_lowercase();
+    let is_numeric = matches!(
+        sql_type.as_str(),
+        "tinyint"
+            | "smallint"
+            | "smallserial"
+            | "int2"
+            | "int4"
+            | "integer"
+            | "serial"
+            | "bigint"
+            | "bigserial"
+            | "int8"
+            | "float"
+            | "float4"
+            | "double"
+            | "float8"
+            | "numeric"
+            | "decimal"
+    );
+    let is_temporal = matches!(
+        sql_type.as_str(),
+        "date" | "datetime" | "time" | "timestamp" | "timestamptz" | "timestamptzsqlite"
+    );
+    let is_text = matches!(
+        sql_type.as_str(),
+        "text" | "varchar" | "bpchar" | "char" | "tinytext" | "mediumtext" | "longtext"
+    );
+
+    // a list of booleans is not useful to filter by
+    if sql_type != "bool" {
+        operators.push(FilterOperator {
+            suffix: "_in",
+            method: "eq_any",
+            ty: format!("Vec<{}>", column.ty),
+        });
+    }
+
+    if is_numeric || is_temporal {
+        for (suffix, method) in [("_gt", "gt"), ("_gte", "ge"), ("_lt", "lt"), ("_lte", "le")] {
+            operators.push(FilterOperator {
+                suffix,
+                method,
+                ty: column.ty.clone(),
+            });
+        }
+    }
+
+    if is_text {
+        operators.push(FilterOperator {
+            suffix: "_like",
+            method: "like",
+            ty: "String".to_string(),
+        });
+
+        // "ilike" is only available for postgres
+        if config.is_postgres() {
+            operators.push(FilterOperator {
+                suffix: "_ilike",
+                method: "ilike",
+                ty: "String".to_string(),
+            });
+        }
+    }
+
+    operators
+}
+
+/// Get the columns to order by for `paginate_after`: the configured `cursor_column` (if any) and the primary key(s)
+///
+/// The primary key(s) make the order unique, so rows with the same `cursor_column` value are neither skipped nor repeated
+///
+/// Returns a error if the configured column does not exist, or it cannot be used as a cursor (nullable or array)
+/// and `None` if a primary key cannot be used as a cursor
+#[cfg(feature = "advanced-queries")]
+fn get_cursor_columns<'a>(
+    table: &'a ParsedTableMacro,
+    table_options: &TableOptions<'_>,
+) -> Result<Option<Vec<&'a ParsedColumnMacro>>> {
+    let mut column_names = table.primary_key_column_names();
+
+    if let Some(name) = table_options.get_cursor_column() {
+        let table_name = table.name.to_string();
+        let column = table.columns.iter().find(|c| c.name == name);
+
+        match column {
+            None => {
+                return Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                    "cursor column \"{table_name}.{name}\" does not exist in table \"{table_name}\""
+                ))))
+            }
+            Some(column) if column.is_nullable || column.is_array => {
+                return Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                    "cursor column \"{table_name}.{name}\" cannot be nullable or a array"
+                ))))
+            }
+            Some(_) => (),
+        }
+
+        if !column_names.iter().any(|c| c == name) {
+            column_names.insert(0, name.to_string());
+        }
+    }
+
+    Ok(column_names
+        .iter()
+        .map(|name| {
+            table
+                .columns
+                .iter()
+                .find(|c| c.name == name)
+                .filter(|c| !c.is_nullable && !c.is_array)
+        })
+        .collect())
+}
+
+/// Build the assignments for columns which are managed by `update` itself instead of the Update struct
+///
+/// Example: `updated_at.eq(diesel::dsl::now)` for `touch_on_update` columns, `version.eq(version + 1)` for the `version_column`
+fn build_managed_assignments(
+    table: &ParsedTableMacro,
+    table_options: &TableOptions<'_>,
+) -> Result<Vec<String>> {
+    let mut assignments: Vec<String> = table_options
+        .get_touch_on_update()
+        .iter()
+        .filter(|col| table.columns.iter().any(|c| c.name == col))
+        .map(|col| format!("{col}.eq(diesel::dsl::now)"))
+        .collect();
+
+    if let Some(version_column) = get_version_column(table, table_options)? {
+        let version_column_name = &version_column.name;
+        assignments.push(format!(
+            "{version_column_name}.eq({version_column_name} + 1)"
+        ));
+    }
+
+    Ok(assignments)
+}
+
+/// Get the column used for optimistic locking
+///
+/// Returns `None` if no column is configured,
+/// and a error if the configured column does not exist or cannot be used as a version (not a non-nullable integer)
+fn get_version_column<'a>(
+    table: &'a ParsedTableMacro,
+    table_options: &TableOptions<'_>,
+) -> Result<Option<&'a ParsedColumnMacro>> {
+    let Some(column_name) = table_options.get_version_column() else {
+        return Ok(None);
+    };
+    let table_name = table.name.to_string();
+
+    match table.columns.iter().find(|c| c.name == column_name) {
+        None => Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+            "version column \"{table_name}.{column_name}\" does not exist in table \"{table_name}\""
+        )))),
+        Some(column) if column.is_nullable || column.is_array || !is_integer_type(&column.ty) => {
+            Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                "version column \"{table_name}.{column_name}\" has to be a non-nullable integer"
+            ))))
+        }
+        Some(column) => Ok(Some(column)),
+    }
+}
+
+/// Get whether the given rust type is a (non-nullable, non-array) integer
+fn is_integer_type(ty: &str) -> bool {
+    matches!(
+        ty,
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64"
+    )
+}
+
+/// Get the column which scopes the rows of `table` to a tenant
+///
+/// Returns `None` if no column is configured, or a inherited `tenant_column` (like from `--tenant-column`) does not exist in this table,
+/// and a error if a `tenant_column` set for this table does not exist or the column is a array
+fn get_tenant_column<'a>(
+    table: &'a ParsedTableMacro,
+    config: &GenerationConfig,
+    table_options: &TableOptions<'_>,
+) -> Result<Option<&'a ParsedColumnMacro>> {
+    let Some(column_name) = table_options.get_tenant_column() else {
+        return Ok(None);
+    };
+    let table_name = table.name.to_string();
+
+    match table.columns.iter().find(|c| c.name == column_name) {
+        None if config
+            .get_explicit_table_options(&table_name)
+            .is_some_and(|v| v.tenant_column.is_some()) =>
+        {
+            Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                "tenant column \"{table_name}.{column_name}\" does not exist in table \"{table_name}\""
+            ))))
+        }
+        None => Ok(None),
+        Some(column) if column.is_array => {
+            Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                "tenant column \"{table_name}.{column_name}\" cannot be a array"
+            ))))
+        }
+        Some(column) => Ok(Some(column)),
+    }
+}
+
+/// Get the column which marks a row as soft-deleted
+///
+/// Returns `None` if no column is configured,
+/// and a error if the configured column does not exist or cannot be used for soft-deletion (not nullable or array)
+fn get_soft_delete_column<'a>(
+    table: &'a ParsedTableMacro,
+    table_options: &TableOptions<'_>,
+) -> Result<Option<&'a ParsedColumnMacro>> {
+    let Some(column_name) = table_options.get_soft_delete_column() else {
+        return Ok(None);
+    };
+    let table_name = table.name.to_string();
+
+    match table.columns.iter().find(|c| c.name == column_name) {
+        None => Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+            "soft delete column \"{table_name}.{column_name}\" does not exist in table \"{table_name}\""
+        )))),
+        Some(column) if !column.is_nullable || column.is_array => {
+            Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                "soft delete column \"{table_name}.{column_name}\" has to be nullable and cannot be a array"
+            ))))
+        }
+        Some(column) => Ok(Some(column)),
+    }
+}
+
+/// Get the variants (function name suffix, doc comment suffix) of a read function, depending on soft-deletion
+#[cfg(feature = "advanced-queries")]
+fn soft_delete_variants(
+    soft_delete_column: Option<&ParsedColumnMacro>,
+) -> Vec<(&'static str, &'static str)> {
+    match soft_delete_column {
+        Some(_) => vec![
+            ("", ", excluding soft-deleted rows"),
+            ("_with_deleted", ", including soft-deleted rows"),
+        ],
+        None => vec![("", "")],
+    }
+}
+
+/// Build the functions to load rows of `table` by their parent(s), for all foreign keys
+///
+/// For a foreign key to `users`, this generates `for_user(db, &Users)` using `belonging_to`
+/// and `for_users(db, &[Users])` using `grouped_by`
+fn build_relation_loaders(
+    table: &ParsedTableMacro,
+    config: &GenerationConfig,
+    table_options: &TableOptions<'_>,
+) -> Result<String> {
+    let (async_keyword, await_keyword) = get_async(table_options);
+    let table_name = table.name.to_string();
+    let schema_path = table.schema_path(config);
+    let mut buffer = String::new();
+
+    let (tenant_param, tenant_filter) = match get_tenant_column(table, config, table_options)? {
+        Some(column) => (
+            format!(", param_{name}: {ty}", name = column.name, ty = column.ty),
+            format!(
+                ".filter({schema_path}{table_name}::{name}.eq(&param_{name}))",
+                name = column.name
+            ),
+        ),
+        None => (String::new(), String::new()),
+    };
+    // soft-deleted rows are excluded, like in "read" & "filter"
+    let (soft_delete_filter, doc_suffix) = match get_soft_delete_column(table, table_options)? {
+        Some(column) => (
+            format!(
+                ".filter({schema_path}{table_name}::{name}.is_null())",
+                name = column.name
+            ),
+            ", excluding soft-deleted rows",
+        ),
+        None => (String::new(), ""),
+    };
+    let tenant_filter = format!("{tenant_filter}{soft_delete_filter}");
+
+    for (foreign_table_name, join_column) in table.primary_associations() {
+        let foreign_table_name = foreign_table_name.to_string();
+        let parent_struct_name = foreign_table_name.to_pascal_case();
+        let (single_fn, grouped_fn) = relation_loader_names(&foreign_table_name, config);
+
+        buffer.push_str(&format!(
+            r##"
+    /// Get all rows of `{table_name}` which belong to the given [`{parent_struct_name}`] (by `{join_column}`){doc_suffix}
+    pub{async_keyword} fn {single_fn}(db: &mut ConnectionType{tenant_param}, parent: &{parent_struct_name}) -> diesel::QueryResult<Vec<Self>> {{
+        Self::belonging_to(parent){tenant_filter}.load::<Self>(db){await_keyword}
+    }}
+
+    /// Get all rows of `{table_name}` which belong to any of the given [`{parent_struct_name}`] (by `{join_column}`), grouped by their parent{doc_suffix}
+    pub{async_keyword} fn {grouped_fn}(db: &mut ConnectionType{tenant_param}, parents: &[{parent_struct_name}]) -> diesel::QueryResult<Vec<({parent_struct_name}, Vec<Self>)>> {{
+        let children = Self::belonging_to(parents){tenant_filter}.load::<Self>(db){await_keyword}?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }}
+"##
+        ));
+    }
+
+    // diesel only supports one association per parent table, so the other foreign keys to the same parent
+    // get explicit filters & joins on their column instead of "belonging_to"
+    for (foreign_table_name, join_column) in table.secondary_associations() {
+        let foreign_table_name = foreign_table_name.to_string();
+        let parent_struct_name = foreign_table_name.to_pascal_case();
+        let (single_fn, grouped_fn) = relation_loader_names(&foreign_table_name, config);
+        let join_fn = format!(
+            "with_{}",
+            join_column.strip_suffix("_id").unwrap_or(join_column)
+        );
+        let is_nullable = table
+            .columns
+            .iter()
+            .any(|c| &c.name.to_string() == join_column && c.is_nullable);
+
+        let (child_matches_parent, join_method, parent_key, joined_type) = if is_nullable {
+            (
+                format!("child.{join_column}.as_ref() == Some(parent.id())"),
+                "left_join",
+                format!("diesel::Table::primary_key(&{schema_path}{foreign_table_name}::table).nullable()"),
+                format!("Option<{parent_struct_name}>"),
+            )
+        } else {
+            (
+                format!("&child.{join_column} == parent.id()"),
+                "inner_join",
+                format!("diesel::Table::primary_key(&{schema_path}{foreign_table_name}::table)"),
+                parent_struct_name.clone(),
+            )
+        };
+
+        buffer.push_str(&format!(
+            r##"
+    /// Get all rows of `{table_name}` which reference the given [`{parent_struct_name}`] by `{join_column}`{doc_suffix}
+    pub{async_keyword} fn {single_fn}_by_{join_column}(db: &mut ConnectionType{tenant_param}, parent: &{parent_struct_name}) -> diesel::QueryResult<Vec<Self>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {table_name}.filter({join_column}.eq(parent.id())){tenant_filter}.load::<Self>(db){await_keyword}
+    }}
+
+    /// Get all rows of `{table_name}` which reference any of the given [`{parent_struct_name}`] by `{join_column}`, grouped by their parent{doc_suffix}
+    pub{async_keyword} fn {grouped_fn}_by_{join_column}(db: &mut ConnectionType{tenant_param}, parents: &[{parent_struct_name}]) -> diesel::QueryResult<Vec<({parent_struct_name}, Vec<Self>)>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        let children = {table_name}.filter({join_column}.eq_any(parents.iter().map(|parent| parent.id()))){tenant_filter}.load::<Self>(db){await_keyword}?;
+
+        Ok(parents
+            .iter()
+            .map(|parent| {{
+                let matching = children.iter().filter(|child| {child_matches_parent}).cloned().collect();
+                (parent.clone(), matching)
+            }})
+            .collect())
+    }}
+
+    /// Get all rows of `{table_name}` together with the [`{parent_struct_name}`] referenced by `{join_column}`{doc_suffix}
+    pub{async_keyword} fn {join_fn}(db: &mut ConnectionType{tenant_param}) -> diesel::QueryResult<Vec<(Self, {joined_type})>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {table_name}
+            .{join_method}({schema_path}{foreign_table_name}::table.on({join_column}.eq({parent_key}))){tenant_filter}
+            .load::<(Self, {joined_type})>(db){await_keyword}
+    }}
+"##
+        ));
+    }
+
+    Ok(buffer)
+}
+
+/// Get the function names for loading rows by a parent table (single, grouped)
+///
+/// Uses the parent's configured `singular_name` for the single function, if any
+///
+/// Example: `users` with `singular_name = "user"` -> (`for_user`, `for_users`), `users` -> (`for_users`, `for_users_grouped`)
+fn relation_loader_names(foreign_table_name: &str, config: &GenerationConfig) -> (String, String) {
+    let singular = config
+        .table(foreign_table_name)
+        .get_singular_name()
+        .map(|name| name.to_snake_case());
+    let foreign_table_name = foreign_table_name.to_snake_case();
+
+    match singular {
+        Some(singular) if singular != foreign_table_name => (
+            format!("for_{singular}"),
+            format!("for_{foreign_table_name}"),
+        ),
+        _ => (
+            format!("for_{foreign_table_name}"),
+            format!("for_{foreign_table_name}_grouped"),
+        ),
+    }
+}
+
+/// Build the `upsert` function (insert, or update the existing row on conflict)
+///
+/// Uses `on_conflict(target).do_update()` for postgres & sqlite and `on_conflict(DuplicatedKeys)` (`ON DUPLICATE KEY UPDATE`) for mysql.
+/// The conflict target defaults to the primary key(s).
+/// If there is nothing to update (like a table of only keys), `do_nothing()` is used and the existing row is read instead.
+///
+/// Returns a error if not all conflict target columns are set in the Create struct.
+/// With a tenant column, the conflict target has to include it, otherwise a conflicting row of another tenant could be updated
+fn build_upsert_fn(
+    table: &ParsedTableMacro,
+    config: &GenerationConfig,
+    table_options: &TableOptions<'_>,
+    create_struct: &Struct,
+    update_struct: &Struct,
+) -> Result<String> {
+    let conflict_columns: Vec<String> = match table_options.get_upsert_conflict_columns() {
+        Some(cols) => cols.iter().map(|v| v.to_string()).collect(),
+        None => table.primary_key_column_names(),
+    };
+    let create_fields: Vec<String> = create_struct.fields().into_iter().map(|f| f.name).collect();
+    let update_fields: Vec<String> = update_struct.fields().into_iter().map(|f| f.name).collect();
+    let tenant_column = get_tenant_column(table, config, table_options)?;
+    let tenant_column_name = tenant_column.map(|c| c.name.to_string());
+    let table_name = table.name.to_string();
+    let upsert_error = |reason: String| {
+        Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+            "Cannot generate \"upsert\" for table \"{table_name}\": {reason}"
+        ))))
+    };
+
+    if let Some(tenant_column_name) = &tenant_column_name {
+        // mysql does not support a conflict target, so any unique key (of any tenant) could conflict
+        if config.is_mysql() {
+            return upsert_error(format!("mysql does not support a conflict target, so rows of other tenants (tenant column \"{tenant_column_name}\") could be updated"));
+        }
+
+        if !conflict_columns.contains(tenant_column_name) {
+            return upsert_error(format!("the conflict columns have to include the tenant column \"{tenant_column_name}\" (set \"upsert_conflict_columns\")"));
+        }
+    }
+
+    if conflict_columns.is_empty() {
+        return upsert_error(
+            "there are no conflict columns (set \"upsert_conflict_columns\")".to_string(),
+        );
+    }
+
+    // the tenant is not part of the Create struct, but a parameter
+    if let Some(column) = conflict_columns
+        .iter()
+        .find(|c| !create_fields.contains(c) && tenant_column_name.as_ref() != Some(*c))
+    {
+        return upsert_error(format!("the conflict column \"{column}\" is not part of the Create struct (like a autogenerated column), set \"upsert_conflict_columns\" to other unique columns"));
+    }
+
+    // only columns which are both insertable and updatable are set on conflict
+    let mut assignments: Vec<String> = create_fields
+        .iter()
+        .filter(|f| update_fields.contains(f) && !conflict_columns.contains(f))
+        .map(|f| format!("{f}.eq(&item.{f})"))
+        .collect();
+
+    // nothing to update, for example a table of only keys
+    let do_nothing = assignments.is_empty();
+
+    assignments.extend(build_managed_assignments(table, table_options)?);
+
+    let assignments = match assignments.as_slice() {
+        [single] => single.clone(),
+        _ => format!("({})", assignments.join(", ")),
+    };
+    let conflict_target = if config.is_mysql() {
+        // mysql does not support a conflict target, "ON DUPLICATE KEY UPDATE" applies to any unique key
+        "diesel::dsl::DuplicatedKeys".to_string()
+    } else if let [single] = conflict_columns.as_slice() {
+        single.clone()
+    } else {
+        format!("({})", conflict_columns.join(", "))
+    };
+    let conflict_columns_doc = conflict_columns
+        .iter()
+        .map(|c| format!("`{c}`"))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let reread_filter = conflict_columns
+        .iter()
+        .map(|c| {
+            if tenant_column_name.as_ref() == Some(c) {
+                format!("filter({c}.eq(&param_{c}))")
+            } else {
+                format!("filter({c}.eq(&item.{c}))")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(".");
+    let (tenant_param, values) = match tenant_column {
+        Some(column) => (
+            format!(", param_{name}: {ty}", name = column.name, ty = column.ty),
+            format!(
+                "values((item, {name}.eq(&param_{name})))",
+                name = column.name
+            ),
+        ),
+        None => (String::new(), "values(item)".to_string()),
+    };
+
+    let schema_path = table.schema_path(config);
+    let create_struct_identifier = &create_struct.identifier;
+    let (async_keyword, await_keyword) = get_async(table_options);
+
+    if do_nothing {
+        let upsert = format!("diesel::insert_into({table_name}).{values}.on_conflict({conflict_target}).do_nothing()");
+
+        return Ok(match config.get_returning_mode() {
+            // "RETURNING" does not return the existing row on "do_nothing", so it is always read again
+            ReturningMode::Returning | ReturningMode::Reread => format!(
+                r##"
+    /// Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`], or keep the existing row on a conflict of {conflict_columns_doc}
+    pub{async_keyword} fn upsert(db: &mut ConnectionType{tenant_param}, item: &{create_struct_identifier}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {upsert}.execute(db){await_keyword}?;
+        {table_name}.{reread_filter}.first::<Self>(db){await_keyword}
+    }}
+"##
+            ),
+            ReturningMode::Execute => format!(
+                r##"
+    /// Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`], or keep the existing row on a conflict of {conflict_columns_doc}, returning the number of inserted rows
+    pub{async_keyword} fn upsert(db: &mut ConnectionType{tenant_param}, item: &{create_struct_identifier}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {upsert}.execute(db){await_keyword}
+    }}
+"##
+            ),
+        });
+    }
+
+    let upsert = format!("diesel::insert_into({table_name}).{values}.on_conflict({conflict_target}).do_update().set({assignments})");
+
+    Ok(match config.get_returning_mode() {
+        ReturningMode::Returning => format!(
+            r##"
+    /// Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`], or update the existing row on a conflict of {conflict_columns_doc}
+    pub{async_keyword} fn upsert(db: &mut ConnectionType{tenant_param}, item: &{create_struct_identifier}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {upsert}.get_result::<Self>(db){await_keyword}
+    }}
+"##
+        ),
+        ReturningMode::Reread => format!(
+            r##"
+    /// Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`], or update the existing row on a conflict of {conflict_columns_doc}
+    ///
+    /// The row is read again afterwards, because the backend does not support `RETURNING`
+    pub{async_keyword} fn upsert(db: &mut ConnectionType{tenant_param}, item: &{create_struct_identifier}) -> diesel::QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {upsert}.execute(db){await_keyword}?;
+        {table_name}.{reread_filter}.first::<Self>(db){await_keyword}
+    }}
+"##
+        ),
+        ReturningMode::Execute => format!(
+            r##"
+    /// Insert a new row into `{table_name}` with a given [`{create_struct_identifier}`], or update the existing row on a conflict of {conflict_columns_doc}, returning the number of affected rows
+    pub{async_keyword} fn upsert(db: &mut ConnectionType{tenant_param}, item: &{create_struct_identifier}) -> diesel::QueryResult<usize> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {upsert}.execute(db){await_keyword}
+    }}
+"##
+        ),
+    })
+}
+
+/// Build the filter to read a row again after it was inserted, for backends without `RETURNING`
+///
+/// Uses the primary key(s) from the Create struct if all are set there,
+/// otherwise `LAST_INSERT_ID()` (mysql) for a single autogenerated integer (`AUTO_INCREMENT`) primary key
+///
+/// Returns a error if the row cannot be identified again
+fn build_create_reread_filter(
+    table: &ParsedTableMacro,
+    config: &GenerationConfig,
+    table_options: &TableOptions<'_>,
+) -> Result<String> {
+    let autogenerated_columns = table_options.get_autogenerated_columns();
+    let primary_keys = table.primary_key_column_names();
+    let tenant_column =
+        get_tenant_column(table, config, table_options)?.map(|c| c.name.to_string());
+    let table_name = table.name.to_string();
+
+    if primary_keys
+        .iter()
+        .all(|pk| !autogenerated_columns.contains(&pk.as_str()))
+        && !primary_keys.is_empty()
+    {
+        return Ok(primary_keys
+            .iter()
+            .map(|pk| {
+                // the tenant is not part of the Create struct, but a parameter
+                if tenant_column.as_ref() == Some(pk) {
+                    format!("filter({pk}.eq(&param_{pk}))")
+                } else {
+                    format!("filter({pk}.eq(&item.{pk}))")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("."));
+    }
+
+    if let [pk] = primary_keys.as_slice() {
+        let column = table
+            .columns
+            .iter()
+            .find(|c| c.name == pk)
+            .expect("Primary key column doesn't exist in table");
+
+        // "LAST_INSERT_ID()" is only set for "AUTO_INCREMENT" columns, which are always integers
+        if is_integer_type(&column.ty) && !column.is_nullable && !column.is_array {
+            return Ok(format!(
+                "filter(diesel::dsl::sql::<diesel::sql_types::Bool>(\"`{}` = LAST_INSERT_ID()\"))",
+                column.column_name
+            ));
+        }
+
+        return Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+            "Cannot read back inserted rows of table \"{table_name}\" without \"RETURNING\": the autogenerated primary key \"{pk}\" is not a integer, so \"LAST_INSERT_ID()\" cannot be used (remove it from the autogenerated columns or use returning mode \"execute\")"
+        ))));
+    }
+
+    Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+        "Cannot read back inserted rows of table \"{table_name}\" without \"RETURNING\": the primary keys ({}) are not all set in the Create struct (remove them from the autogenerated columns or use returning mode \"execute\")",
+        primary_keys.join(", ")
+    ))))
+}
+
+/// Generate common structs
+pub fn generate_common_structs(table_options: &TableOptions<'_>) -> String {
+    #[cfg(feature = "tsync")]
+    let tsync = match table_options.get_tsync() {
+        true => "#[tsync::tsync]\n",
+        false => "",
+    };
+    #[cfg(not(feature = "tsync"))]
+    let tsync = "";
+    let serde_derive = if table_options.get_serde() {
+        derives::SERIALIZE
+    } else {
+        ""
+    };
+    let debug_derive = derives::DEBUG;
+
+    #[allow(unused_mut)] // only modified if feature "advanced-queries" is active
+    let mut common_structs = formatdoc!(
         r##"
         /// Result of a `.paginate` function
         {tsync}#[derive({debug_derive}, {serde_derive})]
@@ -706,16 +2002,192 @@ pub fn generate_common_structs(table_options: &TableOptions<'_>) -> String {
             /// Number of total possible pages, given the `page_size` and `total_items`
             pub num_pages: i64,
         }}
+        "##
+    );
+
+    #[cfg(feature = "advanced-queries")]
+    common_structs.push_str(&formatdoc!(
+        r##"
+
+        /// Result of a `.paginate_after` function
+        {tsync}#[derive({debug_derive}, {serde_derive})]
+        pub struct CursorPaginationResult<T, C> {{
+            /// Resulting items that are from the current page
+            pub items: Vec<T>,
+            /// Cursor to get the next page with, `None` if this is the last page
+            pub next_cursor: Option<C>,
+            /// Size of a page
+            pub page_size: i64,
+        }}
+
+        /// Direction to order by, used by the `OrderBy` structs
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub enum SortDirection {{
+            /// Ascending order (smallest first)
+            #[default]
+            Asc,
+            /// Descending order (largest first)
+            Desc,
+        }}
+        "##
+    ));
+
+    common_structs
+}
+
+/// Generate a rust enum (and its diesel (de)serialization implementations) for a postgres enum
+fn generate_enum(sql_type: &ParsedSqlType, variants: &[&str], config: &GenerationConfig) -> String {
+    let table_options = config.get_default_table_options();
+    let enum_name = &sql_type.name;
+    let postgres_name = &sql_type.postgres_name;
+    let sql_type_path = sql_type.sql_type_path(config);
+
+    #[cfg(feature = "tsync")]
+    let tsync = match table_options.get_tsync() {
+        true => "#[tsync::tsync]\n",
+        false => "",
+    };
+    #[cfg(not(feature = "tsync"))]
+    let tsync = "";
+
+    let mut derives_vec = vec![
+        derives::DEBUG,
+        derives::CLONE,
+        derives::COPY,
+        derives::PARTIALEQ,
+        derives::EQ,
+    ];
+
+    if table_options.get_serde() {
+        derives_vec.extend_from_slice(&[derives::SERIALIZE, derives::DESERIALIZE]);
+    }
+
+    derives_vec.extend_from_slice(&[derives::ASEXPRESSION, derives::FROMSQLROW]);
+
+    let variant_lines = variants
+        .iter()
+        .map(|variant| {
+            let serde_rename = if table_options.get_serde() {
+                format!("\n    #[serde(rename = \"{variant}\")]")
+            } else {
+                String::new()
+            };
+
+            format!(
+                "    /// Variant representing value `{variant}`{serde_rename}\n    {variant_ident},",
+                variant_ident = variant.to_pascal_case()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let to_sql_arms = variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "            {enum_name}::{variant_ident} => out.write_all(b\"{variant}\")?,",
+                variant_ident = variant.to_pascal_case()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let from_sql_arms = variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "            b\"{variant}\" => Ok({enum_name}::{variant_ident}),",
+                variant_ident = variant.to_pascal_case()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    formatdoc!(
+        r##"
+        /// Enum representing the postgres enum `{postgres_name}`
+        {tsync}#[derive({derives})]
+        #[diesel(sql_type = {sql_type_path})]
+        pub enum {enum_name} {{
+        {variant_lines}
+        }}
+
+        impl diesel::serialize::ToSql<{sql_type_path}, diesel::pg::Pg> for {enum_name} {{
+            fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {{
+                use std::io::Write;
+
+                match *self {{
+        {to_sql_arms}
+                }}
+
+                Ok(diesel::serialize::IsNull::No)
+            }}
+        }}
+
+        impl diesel::deserialize::FromSql<{sql_type_path}, diesel::pg::Pg> for {enum_name} {{
+            fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {{
+                match bytes.as_bytes() {{
+        {from_sql_arms}
+                    _ => Err(format!("Unrecognized variant for enum `{postgres_name}`: {{:?}}", String::from_utf8_lossy(bytes.as_bytes())).into()),
+                }}
+            }}
+        }}
         "##,
-        serde_derive = if table_options.get_serde() {
-            derives::SERIALIZE
-        } else {
-            ""
-        },
-        debug_derive = derives::DEBUG
+        derives = derives_vec.join(", "),
     )
 }
 
+/// Generate the contents of the "enums.rs" file for all postgres enums which have variants configured
+///
+/// Returns [None] if there are no enums to generate
+///
+/// Enums of a schema module (like `pub mod tenant { }`) are generated in a module of the same name
+pub fn generate_enums(sql_types: &[ParsedSqlType], config: &GenerationConfig) -> Option<String> {
+    let mut enums = Vec::new();
+    // schema modules in order of their first appearance, with their enums
+    let mut module_enums: Vec<(String, Vec<String>)> = Vec::new();
+
+    for sql_type in sql_types {
+        let Some(variants) = config.get_enum_variants(&sql_type.postgres_name) else {
+            continue;
+        };
+        let code = generate_enum(sql_type, variants, config);
+
+        match &sql_type.schema_module {
+            None => enums.push(code),
+            Some(module) => {
+                let module = module.to_string();
+
+                match module_enums.iter_mut().find(|(name, _)| name == &module) {
+                    Some((_, codes)) => codes.push(code),
+                    None => module_enums.push((module, vec![code])),
+                }
+            }
+        }
+    }
+
+    for (module, codes) in module_enums {
+        let indented = codes
+            .join("\n")
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("    {line}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        enums.push(format!("pub mod {module} {{\n{indented}\n}}\n"));
+    }
+
+    if enums.is_empty() {
+        return None;
+    }
+
+    Some(format!("{FILE_SIGNATURE}\n\n{}", enums.join("\n")))
+}
+
 /// Generate connection-type type
 pub fn generate_connection_type(config: &GenerationConfig) -> String {
     format!(
@@ -732,12 +2204,13 @@ fn build_imports(table: &ParsedTableMacro, config: &GenerationConfig) -> String
     imports_vec.push("#[allow(unused)]\nuse crate::diesel::*;".into());
 
     let table_options = config.table(&table.name.to_string());
-    imports_vec.extend(table.foreign_keys.iter().map(|fk| {
+    // multiple foreign keys may reference the same parent table, which only needs to be imported once
+    imports_vec.extend(table.primary_associations().into_iter().map(|fk| {
         format!(
             "use {model_path}{foreign_table_name_model}::{singular_struct_name};",
             foreign_table_name_model = get_table_module_name(&fk.0.to_string()),
             singular_struct_name = fk.0.to_string().to_pascal_case(),
-            model_path = config.get_model_path()
+            model_path = table.model_path(config)
         )
     }));
     #[cfg(feature = "async")]
@@ -746,7 +2219,7 @@ fn build_imports(table: &ParsedTableMacro, config: &GenerationConfig) -> String
     }
 
     // no "::" because that is already included in the schema_path
-    imports_vec.push(format!("use {}*;", config.get_schema_path()));
+    imports_vec.push(format!("use {}*;", table.schema_path(config)));
 
     if config.any_once_option() {
         imports_vec.push(format!("use {}common::*;", config.get_model_path()));
@@ -762,10 +2235,15 @@ fn build_imports(table: &ParsedTableMacro, config: &GenerationConfig) -> String
 }
 
 /// Generate a full file for a given diesel table
-pub fn generate_for_table(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+pub fn generate_for_table(table: &ParsedTableMacro, config: &GenerationConfig) -> Result<String> {
     // early to ensure the table options are set for the current table
     let table_options = config.table(&table.name.to_string());
 
+    // the structs depend on the configured columns too, so they are checked even if no functions are generated
+    get_soft_delete_column(table, &table_options)?;
+    get_version_column(table, &table_options)?;
+    get_tenant_column(table, config, &table_options)?;
+
     let mut ret_buffer = format!("{FILE_SIGNATURE}\n\n");
 
     // first push imports
@@ -792,8 +2270,8 @@ pub fn generate_for_table(table: &ParsedTableMacro, config: &GenerationConfig) -
     // third and lastly, push functions - if enabled
     if table_options.get_fns() {
         ret_buffer.push('\n');
-        ret_buffer.push_str(build_table_fns(table, config, create_struct, update_struct).as_str());
+        ret_buffer.push_str(build_table_fns(table, config, create_struct, update_struct)?.as_str());
     }
 
-    ret_buffer
+    Ok(ret_buffer)
 }
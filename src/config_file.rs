@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::error::{ErrorEnum, IOErrorToError};
+use crate::{
+    BytesType, Error, GenerationConfigOpts, NetworkAddressType, Result, ReturningMode, StringType,
+    TableOptions,
+};
+
+/// Representation of a `dsync.toml` config file
+///
+/// ```toml
+/// schema_path = "crate::schema::"
+/// once_common_structs = true
+///
+/// [default]
+/// autogenerated_columns = ["id", "created_at"]
+///
+/// [tables.todos]
+/// create_str_type = "cow"
+///
+/// [tables.todos.column_types]
+/// status = "crate::TodoStatus"
+///
+/// [tables.todos.foreign_keys]
+/// reviewer_id = "users"
+///
+/// [tables.__diesel_schema_migrations]
+/// ignore = true
+///
+/// [enum_variants]
+/// status = ["active", "inactive"]
+///
+/// [type_overrides]
+/// Numeric = "rust_decimal::Decimal"
+/// ```
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Options for a specific table, keyed by the table name
+    #[serde(default)]
+    pub tables: HashMap<String, TableOptionsFile>,
+    /// Default table options, merged into every table (options set in `tables` take precedence)
+    #[serde(default)]
+    pub default: TableOptionsFile,
+    /// Diesel schema import path
+    pub schema_path: Option<String>,
+    /// Dsync model import path
+    pub model_path: Option<String>,
+    /// Generate common structs only once in a "common.rs" file
+    pub once_common_structs: Option<bool>,
+    /// Generate the "ConnectionType" type only once in a "common.rs" file
+    pub once_connection_type: Option<bool>,
+    /// Prefixes to treat tables as readonly
+    #[serde(default)]
+    pub readonly_prefixes: Vec<String>,
+    /// Suffixes to treat tables as readonly
+    #[serde(default)]
+    pub readonly_suffixes: Vec<String>,
+    /// Variants of postgres enums, keyed by the postgres type name
+    #[serde(default)]
+    pub enum_variants: HashMap<String, Vec<String>>,
+    /// Rust types to use for diesel sql types, keyed by the sql type name
+    #[serde(default)]
+    pub type_overrides: HashMap<String, String>,
+    /// Rust type to use for postgres `Inet` & `Cidr` columns
+    pub network_address_type: Option<NetworkAddressType>,
+    /// How generated `create` & `update` functions get the affected row back
+    pub returning_mode: Option<ReturningMode>,
+}
+
+/// Owned representation of [TableOptions] as it is found in a config file
+///
+/// Any option that is not set is left up to the defaults
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TableOptionsFile {
+    pub ignore: Option<bool>,
+    pub autogenerated_columns: Option<Vec<String>>,
+    #[cfg(feature = "tsync")]
+    pub tsync: Option<bool>,
+    #[cfg(feature = "async")]
+    pub use_async: Option<bool>,
+    pub use_serde: Option<bool>,
+    pub fns: Option<bool>,
+    pub create_str_type: Option<StringType>,
+    pub update_str_type: Option<StringType>,
+    pub create_bytes_type: Option<BytesType>,
+    pub update_bytes_type: Option<BytesType>,
+    pub single_model_file: Option<bool>,
+    pub read_only: Option<bool>,
+    pub column_types: Option<HashMap<String, String>>,
+    pub foreign_keys: Option<HashMap<String, String>>,
+    pub upsert: Option<bool>,
+    pub upsert_conflict_columns: Option<Vec<String>>,
+    pub cursor_column: Option<String>,
+    pub singular_name: Option<String>,
+    pub soft_delete_column: Option<String>,
+    pub touch_on_update: Option<Vec<String>>,
+    pub immutable_columns: Option<Vec<String>>,
+    pub version_column: Option<String>,
+    pub tenant_column: Option<String>,
+}
+
+impl ConfigFile {
+    /// Read and parse a config file from `path`
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).attach_path_err(path)?;
+
+        toml::from_str(&contents).map_err(|err| {
+            Error::new(ErrorEnum::ConfigFileError(
+                Box::new(err),
+                path.to_string_lossy().to_string(),
+            ))
+        })
+    }
+
+    /// Convert the file representation into [GenerationConfigOpts], borrowing from `self`
+    pub fn to_generation_config_opts(&self) -> GenerationConfigOpts<'_> {
+        let defaults = GenerationConfigOpts::default();
+
+        GenerationConfigOpts {
+            table_options: self
+                .tables
+                .iter()
+                .map(|(name, opts)| (name.as_str(), opts.to_table_options()))
+                .collect(),
+            default_table_options: self.default.to_table_options(),
+            schema_path: self.schema_path.clone().unwrap_or(defaults.schema_path),
+            model_path: self.model_path.clone().unwrap_or(defaults.model_path),
+            once_common_structs: self
+                .once_common_structs
+                .unwrap_or(defaults.once_common_structs),
+            once_connection_type: self
+                .once_connection_type
+                .unwrap_or(defaults.once_connection_type),
+            readonly_prefixes: self.readonly_prefixes.clone(),
+            readonly_suffixes: self.readonly_suffixes.clone(),
+            enum_variants: self
+                .enum_variants
+                .iter()
+                .map(|(name, variants)| {
+                    (name.as_str(), variants.iter().map(String::as_str).collect())
+                })
+                .collect(),
+            type_overrides: self
+                .type_overrides
+                .iter()
+                .map(|(name, ty)| (name.as_str(), ty.as_str()))
+                .collect(),
+            network_address_type: self
+                .network_address_type
+                .unwrap_or(defaults.network_address_type),
+            returning_mode: self.returning_mode,
+        }
+    }
+}
+
+impl TableOptionsFile {
+    /// Convert the file representation into [TableOptions], borrowing from `self`
+    pub fn to_table_options(&self) -> TableOptions<'_> {
+        TableOptions {
+            ignore: self.ignore,
+            autogenerated_columns: self
+                .autogenerated_columns
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            #[cfg(feature = "tsync")]
+            tsync: self.tsync,
+            #[cfg(feature = "async")]
+            use_async: self.use_async,
+            use_serde: self.use_serde,
+            fns: self.fns,
+            create_str_type: self.create_str_type,
+            update_str_type: self.update_str_type,
+            create_bytes_type: self.create_bytes_type,
+            update_bytes_type: self.update_bytes_type,
+            single_model_file: self.single_model_file,
+            read_only: self.read_only,
+            column_types: self.column_types.as_ref().map(|v| {
+                v.iter()
+                    .map(|(name, ty)| (name.as_str(), ty.as_str()))
+                    .collect()
+            }),
+            foreign_keys: self.foreign_keys.as_ref().map(|v| {
+                v.iter()
+                    .map(|(column, table)| (column.as_str(), table.as_str()))
+                    .collect()
+            }),
+            upsert: self.upsert,
+            upsert_conflict_columns: self
+                .upsert_conflict_columns
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            cursor_column: self.cursor_column.as_deref(),
+            singular_name: self.singular_name.as_deref(),
+            soft_delete_column: self.soft_delete_column.as_deref(),
+            touch_on_update: self
+                .touch_on_update
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            immutable_columns: self
+                .immutable_columns
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            version_column: self.version_column.as_deref(),
+            tenant_column: self.tenant_column.as_deref(),
+        }
+    }
+}
@@ -26,6 +26,8 @@ macro_rules! fn_string {
 pub struct Error {
     /// The actual error
     source: ErrorEnum,
+    /// The location in the input (like the diesel schema file) that caused the error, if known
+    location: Option<Box<SourceLocation>>,
     #[cfg(feature = "backtrace")]
     /// The backtrace for the error
     backtrace: Backtrace,
@@ -36,6 +38,7 @@ impl Error {
     pub fn new(source: ErrorEnum) -> Self {
         Self {
             source,
+            location: None,
             #[cfg(feature = "backtrace")]
             backtrace: Backtrace::capture(),
         }
@@ -47,6 +50,76 @@ impl Error {
         &self.backtrace
     }
 
+    /// Attach a [SourceLocation] to this error, replacing any existing location
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(Box::new(location));
+        self
+    }
+
+    /// Get the [SourceLocation] that is stored, if any
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_deref()
+    }
+
+    /// Attach the file path and the offending line to the stored [SourceLocation], if there is one
+    pub(crate) fn attach_source<P: AsRef<Path>>(mut self, path: P, contents: &str) -> Self {
+        if let Some(location) = self.location.as_mut() {
+            location.file = Some(path.as_ref().to_string_lossy().to_string());
+            location.line_contents = contents
+                .lines()
+                .nth(location.line.saturating_sub(1))
+                .map(str::to_string);
+        }
+
+        self
+    }
+
+    /// Render this error in a rustc-like format, including the offending line if known
+    ///
+    /// ```txt
+    /// error: UnsupportedType: ...
+    ///  --> src/schema.rs:4:17
+    ///   |
+    /// 4 |         ip -> Inet,
+    ///   |               ^^^^
+    ///   |
+    ///   = note: in table `users`, column `ip`
+    /// ```
+    pub fn render(&self) -> String {
+        let Some(location) = self.location() else {
+            return format!("error: {}", self.source);
+        };
+
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let mut out = format!(
+            "error: {}\n{gutter}--> {}:{}:{}\n",
+            self.source,
+            location.file.as_deref().unwrap_or("<input>"),
+            location.line,
+            // columns are 0-based in proc_macro2, but 1-based for humans
+            location.column + 1
+        );
+
+        if let Some(line_contents) = &location.line_contents {
+            let padding: String = line_contents
+                .chars()
+                .take(location.column)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            out.push_str(&format!(
+                "{gutter} |\n{line_number} | {line_contents}\n{gutter} | {padding}{}\n",
+                "^".repeat(location.len.max(1))
+            ));
+        }
+
+        if let Some(note) = location.note() {
+            out.push_str(&format!("{gutter} |\n{gutter} = note: {note}\n"));
+        }
+
+        out
+    }
+
     fn_string!(other, ErrorEnum::Other);
     fn_string!(
         unsupported_schema_format,
@@ -81,7 +154,92 @@ impl Error {
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.source.fmt(f)
+        self.source.fmt(f)?;
+
+        if let Some(location) = self.location() {
+            write!(f, "; {location}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Location in the input that caused a [Error], like a column in the diesel schema file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Path of the input file, if known
+    pub file: Option<String>,
+    /// Line in the input, 1-based
+    pub line: usize,
+    /// Column in the line, 0-based
+    pub column: usize,
+    /// Length of the offending span in characters, if on a single line
+    pub len: usize,
+    /// Contents of the offending line, if known
+    pub line_contents: Option<String>,
+    /// Name of the table that was being parsed, if any
+    pub table: Option<String>,
+    /// Name of the column that was being parsed, if any
+    pub column_name: Option<String>,
+}
+
+impl SourceLocation {
+    /// Set the table name that was being parsed
+    pub fn table<S: ToString>(mut self, table: S) -> Self {
+        self.table = Some(table.to_string());
+        self
+    }
+
+    /// Set the column name that was being parsed
+    pub fn column_name<S: ToString>(mut self, column_name: S) -> Self {
+        self.column_name = Some(column_name.to_string());
+        self
+    }
+
+    /// Get a human readable note about which table / column was being parsed
+    fn note(&self) -> Option<String> {
+        match (&self.table, &self.column_name) {
+            (Some(table), Some(column)) => Some(format!("in table `{table}`, column `{column}`")),
+            (Some(table), None) => Some(format!("in table `{table}`")),
+            (None, Some(column)) => Some(format!("in column `{column}`")),
+            (None, None) => None,
+        }
+    }
+}
+
+impl From<proc_macro2::Span> for SourceLocation {
+    fn from(span: proc_macro2::Span) -> Self {
+        let start = span.start();
+        let end = span.end();
+
+        Self {
+            line: start.line,
+            column: start.column,
+            len: if start.line == end.line {
+                end.column.saturating_sub(start.column)
+            } else {
+                1
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "At \"{}:{}:{}\"",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column + 1
+        )?;
+
+        if let Some(note) = self.note() {
+            write!(f, " ({note})")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -126,6 +284,11 @@ pub enum ErrorEnum {
     #[error("InvalidGenerationConfig: {0}")]
     InvalidGenerationConfig(String),
 
+    /// Variant for a config file that could not be parsed
+    /// Argument 1 (String) is the path of the config file
+    #[error("ConfigFileError: {0}; Path \"{1}\"")]
+    ConfigFileError(Box<toml::de::Error>, String),
+
     /// Variant for Other messages
     #[error("Other: {0}")]
     Other(String),
@@ -171,3 +334,112 @@ impl<T> IOErrorToError<T> for std::result::Result<T, std::io::Error> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GenerationConfig;
+
+    fn config() -> GenerationConfig<'static> {
+        #[cfg(not(feature = "advanced-queries"))]
+        return GenerationConfig::new("diesel::pg::PgConnection");
+        #[cfg(feature = "advanced-queries")]
+        return GenerationConfig::new("diesel::pg::PgConnection", "diesel::pg::Pg");
+    }
+
+    /// Parse `schema` and return the error, with the source attached like [crate::generate_files] does
+    fn parse_error(schema: &str) -> Error {
+        crate::parser::parse_and_generate_code(schema, &config())
+            .expect_err("schema should not parse")
+            .attach_source("schema.rs", schema)
+    }
+
+    #[test]
+    fn render_with_location() {
+        let err = Error::unsupported_type("Inet").with_location(
+            SourceLocation {
+                line: 4,
+                column: 14,
+                len: 4,
+                ..Default::default()
+            }
+            .table("users")
+            .column_name("ip"),
+        );
+        let err = err.attach_source(
+            "src/schema.rs",
+            "diesel::table! {\n    users (id) {\n        id -> Int4,\n        ip -> Inet,\n    }\n}\n",
+        );
+
+        assert_eq!(
+            err.render(),
+            "error: UnsupportedType: Inet\n \
+             --> src/schema.rs:4:15\n  \
+             |\n\
+             4 |         ip -> Inet,\n  \
+             |               ^^^^\n  \
+             |\n  \
+             = note: in table `users`, column `ip`\n"
+        );
+    }
+
+    #[test]
+    fn render_without_location() {
+        assert_eq!(
+            Error::unsupported_type("Inet").render(),
+            "error: UnsupportedType: Inet"
+        );
+    }
+
+    #[test]
+    fn lex_error_location() {
+        let err = parse_error("diesel::table! {\n    users (id) {\n        id -> \"Int4,\n");
+        let location = err.location().expect("error should have a location");
+
+        assert!(matches!(err.source, ErrorEnum::UnsupportedSchemaFormat(_)));
+        assert_eq!(location.file.as_deref(), Some("schema.rs"));
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 14);
+        assert_eq!(location.note(), None);
+    }
+
+    #[test]
+    fn partially_defined_column_location() {
+        let err = parse_error(
+            "diesel::table! {\n    users (id) {\n        id -> Int4,\n        name ->\n    }\n}\n",
+        );
+        let location = err.location().expect("error should have a location");
+
+        assert!(
+            matches!(&err.source, ErrorEnum::UnsupportedSchemaFormat(msg) if msg.contains("partially defined"))
+        );
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, 8);
+        assert_eq!(location.len, 4);
+        assert_eq!(
+            location.note().as_deref(),
+            Some("in table `users`, column `name`")
+        );
+    }
+
+    #[test]
+    fn unsigned_text_location() {
+        let err = parse_error(
+            "diesel::table! {\n    users (id) {\n        id -> Int4,\n        name -> Unsigned<Text>,\n    }\n}\n",
+        );
+        let location = err.location().expect("error should have a location");
+
+        assert!(matches!(err.source, ErrorEnum::UnsupportedType(_)));
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, 25);
+        assert_eq!(location.len, 4);
+        assert_eq!(
+            location.line_contents.as_deref(),
+            Some("        name -> Unsigned<Text>,")
+        );
+        assert_eq!(
+            location.note().as_deref(),
+            Some("in table `users`, column `name`")
+        );
+    }
+}
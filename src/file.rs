@@ -4,6 +4,8 @@ use std::path::PathBuf;
 pub struct MarkedFile {
     /// File contents that were read / need to be written
     file_contents: String,
+    /// File contents as they were read, before any modifications
+    original_contents: String,
     /// Path of the read / to write file
     pub path: PathBuf,
     modified: bool,
@@ -21,6 +23,7 @@ impl MarkedFile {
         };
         Ok(MarkedFile {
             path,
+            original_contents: file_contents.clone(),
             file_contents,
             modified: false,
         })
@@ -30,6 +33,10 @@ impl MarkedFile {
         &self.file_contents
     }
 
+    pub fn get_original_contents(&self) -> &str {
+        &self.original_contents
+    }
+
     pub fn is_modified(&self) -> bool {
         self.modified
     }
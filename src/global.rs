@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 /// Available options for string types
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StringType {
     /// Use `String`
     #[default]
@@ -33,7 +34,8 @@ impl StringType {
 }
 
 /// Available options for bytes types
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum BytesType {
     /// Use `Vec<u8>`
     #[default]
@@ -64,45 +66,132 @@ impl BytesType {
     }
 }
 
+/// Available options for the rust type of postgres `Inet` & `Cidr` columns
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkAddressType {
+    /// Use `ipnetwork::IpNetwork` (diesel feature `network-address`)
+    #[default]
+    IpNetwork,
+    /// Use `ipnet::IpNet` (diesel feature `ipnet-address`)
+    IpNet,
+}
+
+impl NetworkAddressType {
+    /// Get the current [NetworkAddressType] as a rust type string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkAddressType::IpNetwork => "ipnetwork::IpNetwork",
+            NetworkAddressType::IpNet => "ipnet::IpNet",
+        }
+    }
+}
+
+/// Available strategies for getting the row back from generated `create` & `update` functions
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReturningMode {
+    /// Use a `RETURNING` clause (`get_result`)
+    ///
+    /// Supported by postgres and sqlite (diesel feature `returning_clauses_for_sqlite_3_35`)
+    #[default]
+    Returning,
+    /// Use `execute` and read the row again by its primary key(s) or `LAST_INSERT_ID()` (mysql)
+    Reread,
+    /// Only use `execute` and return the number of affected rows
+    Execute,
+}
+
 /// Options for a individual table
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TableOptions<'a> {
     /// Ignore a specific table
-    ignore: Option<bool>,
+    pub(crate) ignore: Option<bool>,
     /// Names used for autogenerated columns which are NOT primary keys (for example: `created_at`, `updated_at`, etc.).
-    autogenerated_columns: Option<Vec<&'a str>>,
+    pub(crate) autogenerated_columns: Option<Vec<&'a str>>,
 
     #[cfg(feature = "tsync")]
     /// Adds #[tsync] attribute to structs (see <https://github.com/Wulf/tsync>)
-    tsync: Option<bool>,
+    pub(crate) tsync: Option<bool>,
 
     #[cfg(feature = "async")]
     /// Uses diesel_async for generated functions (see <https://github.com/weiznich/diesel_async>)
-    use_async: Option<bool>,
+    pub(crate) use_async: Option<bool>,
 
     /// Generates `serde::Serialize` and `serde::Deserialize` derive implementations
-    use_serde: bool,
+    pub(crate) use_serde: Option<bool>,
 
     /// Generates the CRUD functions for generated models
-    fns: bool,
+    pub(crate) fns: Option<bool>,
 
     /// Determines which string type to use for Create* structs
-    create_str_type: StringType,
+    pub(crate) create_str_type: Option<StringType>,
 
     /// Determines which string type to use for Update* structs
-    update_str_type: StringType,
+    pub(crate) update_str_type: Option<StringType>,
 
     /// Determines which bytes type to use for Create* structs
-    create_bytes_type: BytesType,
+    pub(crate) create_bytes_type: Option<BytesType>,
 
     /// Determines which bytes type to use for Update* structs
-    update_bytes_type: BytesType,
+    pub(crate) update_bytes_type: Option<BytesType>,
 
     /// Only Generate a single model file instead of a directory with "mod.rs" and "generated.rs"
-    single_model_file: bool,
+    pub(crate) single_model_file: Option<bool>,
 
     /// Indicates this table is meant to be read-only (don't generate Update & Create structs)
-    read_only: bool,
+    pub(crate) read_only: Option<bool>,
+
+    /// Rust types to use for specific columns, keyed by the column name (like `email = "crate::types::Email"`)
+    ///
+    /// These take precedence over any type derived from the sql type
+    pub(crate) column_types: Option<HashMap<&'a str, &'a str>>,
+
+    /// Additional foreign keys, keyed by the column name, with the referenced (parent) table as value (like `reviewer_id = "users"`)
+    ///
+    /// For foreign keys which cannot be declared with `diesel::joinable!`, like a second foreign key to the same parent table
+    pub(crate) foreign_keys: Option<HashMap<&'a str, &'a str>>,
+
+    /// Generates a `upsert` function (insert, or update on conflict)
+    pub(crate) upsert: Option<bool>,
+
+    /// Columns to use as the conflict target for `upsert`, instead of the primary key(s)
+    pub(crate) upsert_conflict_columns: Option<Vec<&'a str>>,
+
+    /// Column to order by for `paginate_after` (keyset pagination), before the primary key(s)
+    ///
+    /// The column has to be not nullable
+    pub(crate) cursor_column: Option<&'a str>,
+
+    /// Singular name of the table (like `category` for `categories`), used to name the relation loaders of its child tables (like `for_category`)
+    ///
+    /// If not set, the table name is used (like `for_categories` & `for_categories_grouped`)
+    pub(crate) singular_name: Option<&'a str>,
+
+    /// Nullable timestamp column which marks a row as deleted (like `deleted_at`)
+    ///
+    /// `delete` sets the column instead of deleting the row, and reads exclude deleted rows by default
+    pub(crate) soft_delete_column: Option<&'a str>,
+
+    /// Columns which are set to the current time on every `update` (like `updated_at`)
+    ///
+    /// These columns are not part of the Update struct
+    pub(crate) touch_on_update: Option<Vec<&'a str>>,
+
+    /// Columns which can only be set on create (like `created_at` or `owner_id`)
+    ///
+    /// These columns are not part of the Update struct
+    pub(crate) immutable_columns: Option<Vec<&'a str>>,
+
+    /// Integer column used for optimistic locking
+    ///
+    /// `update` requires the expected version and increments it, the column is not part of the Update struct
+    pub(crate) version_column: Option<&'a str>,
+
+    /// Column which scopes all rows to a tenant (like `tenant_id`)
+    ///
+    /// All generated functions take the tenant as a parameter and filter by it, the column is not part of the Create & Update structs
+    pub(crate) tenant_column: Option<&'a str>,
 }
 
 impl<'a> TableOptions<'a> {
@@ -125,32 +214,32 @@ impl<'a> TableOptions<'a> {
 
     #[inline]
     pub fn get_serde(&self) -> bool {
-        self.use_serde
+        self.use_serde.unwrap_or(true)
     }
 
     #[inline]
     pub fn get_fns(&self) -> bool {
-        self.fns
+        self.fns.unwrap_or(true)
     }
 
     #[inline]
     pub fn get_create_str_type(&self) -> StringType {
-        self.create_str_type
+        self.create_str_type.unwrap_or_default()
     }
 
     #[inline]
     pub fn get_update_str_type(&self) -> StringType {
-        self.update_str_type
+        self.update_str_type.unwrap_or_default()
     }
 
     #[inline]
     pub fn get_create_bytes_type(&self) -> BytesType {
-        self.create_bytes_type
+        self.create_bytes_type.unwrap_or_default()
     }
 
     #[inline]
     pub fn get_update_bytes_type(&self) -> BytesType {
-        self.update_bytes_type
+        self.update_bytes_type.unwrap_or_default()
     }
 
     #[inline]
@@ -160,12 +249,81 @@ impl<'a> TableOptions<'a> {
 
     #[inline]
     pub fn get_readonly(&self) -> bool {
-        self.read_only
+        self.read_only.unwrap_or_default()
     }
 
     #[inline]
     pub fn get_single_model_file(&self) -> bool {
-        self.single_model_file
+        self.single_model_file.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn get_upsert(&self) -> bool {
+        self.upsert.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn get_upsert_conflict_columns(&self) -> Option<&[&'a str]> {
+        self.upsert_conflict_columns.as_deref()
+    }
+
+    #[inline]
+    pub fn get_cursor_column(&self) -> Option<&'a str> {
+        self.cursor_column
+    }
+
+    #[inline]
+    pub fn get_singular_name(&self) -> Option<&'a str> {
+        self.singular_name
+    }
+
+    #[inline]
+    pub fn get_soft_delete_column(&self) -> Option<&'a str> {
+        self.soft_delete_column
+    }
+
+    #[inline]
+    pub fn get_touch_on_update(&self) -> &[&'a str] {
+        self.touch_on_update.as_deref().unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn get_immutable_columns(&self) -> &[&'a str] {
+        self.immutable_columns.as_deref().unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn get_version_column(&self) -> Option<&'a str> {
+        self.version_column
+    }
+
+    #[inline]
+    pub fn get_tenant_column(&self) -> Option<&'a str> {
+        self.tenant_column
+    }
+
+    #[inline]
+    pub fn get_column_type(&self, column_name: &str) -> Option<&'a str> {
+        self.column_types
+            .as_ref()
+            .and_then(|v| v.get(column_name).copied())
+    }
+
+    /// Get the configured parent table of a foreign key column, if any
+    #[inline]
+    pub fn get_foreign_key(&self, column_name: &str) -> Option<&'a str> {
+        self.foreign_keys
+            .as_ref()
+            .and_then(|v| v.get(column_name).copied())
+    }
+
+    /// Get the columns of all configured foreign keys
+    #[inline]
+    pub fn get_foreign_key_columns(&self) -> Vec<&'a str> {
+        self.foreign_keys
+            .as_ref()
+            .map(|v| v.keys().copied().collect())
+            .unwrap_or_default()
     }
 
     #[inline]
@@ -197,20 +355,95 @@ impl<'a> TableOptions<'a> {
     #[inline]
     pub fn disable_serde(self) -> Self {
         Self {
-            use_serde: false,
+            use_serde: Some(false),
             ..self
         }
     }
 
     #[inline]
     pub fn disable_fns(self) -> Self {
-        Self { fns: false, ..self }
+        Self {
+            fns: Some(false),
+            ..self
+        }
     }
 
     #[inline]
     pub fn single_model_file(self) -> Self {
         Self {
-            single_model_file: true,
+            single_model_file: Some(true),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn upsert(self) -> Self {
+        Self {
+            upsert: Some(true),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn upsert_conflict_columns(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            upsert_conflict_columns: Some(cols),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn cursor_column(self, column_name: &'a str) -> Self {
+        Self {
+            cursor_column: Some(column_name),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn singular_name(self, name: &'a str) -> Self {
+        Self {
+            singular_name: Some(name),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn soft_delete_column(self, column_name: &'a str) -> Self {
+        Self {
+            soft_delete_column: Some(column_name),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn touch_on_update(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            touch_on_update: Some(cols),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn immutable_columns(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            immutable_columns: Some(cols),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn version_column(self, column_name: &'a str) -> Self {
+        Self {
+            version_column: Some(column_name),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn tenant_column(self, column_name: &'a str) -> Self {
+        Self {
+            tenant_column: Some(column_name),
             ..self
         }
     }
@@ -226,7 +459,7 @@ impl<'a> TableOptions<'a> {
     #[inline]
     pub fn create_str_type(self, type_: StringType) -> Self {
         Self {
-            create_str_type: type_,
+            create_str_type: Some(type_),
             ..self
         }
     }
@@ -234,7 +467,7 @@ impl<'a> TableOptions<'a> {
     #[inline]
     pub fn update_str_type(self, type_: StringType) -> Self {
         Self {
-            update_str_type: type_,
+            update_str_type: Some(type_),
             ..self
         }
     }
@@ -242,7 +475,7 @@ impl<'a> TableOptions<'a> {
     #[inline]
     pub fn create_bytes_type(self, type_: BytesType) -> Self {
         Self {
-            create_bytes_type: type_,
+            create_bytes_type: Some(type_),
             ..self
         }
     }
@@ -250,14 +483,38 @@ impl<'a> TableOptions<'a> {
     #[inline]
     pub fn update_bytes_type(self, type_: BytesType) -> Self {
         Self {
-            update_bytes_type: type_,
+            update_bytes_type: Some(type_),
+            ..self
+        }
+    }
+
+    /// Use rust type `ty` for column `column_name`
+    #[inline]
+    pub fn column_type(self, column_name: &'a str, ty: &'a str) -> Self {
+        let mut column_types = self.column_types.unwrap_or_default();
+        column_types.insert(column_name, ty);
+
+        Self {
+            column_types: Some(column_types),
+            ..self
+        }
+    }
+
+    /// Declare column `column_name` as a foreign key to table `foreign_table_name`
+    #[inline]
+    pub fn foreign_key(self, column_name: &'a str, foreign_table_name: &'a str) -> Self {
+        let mut foreign_keys = self.foreign_keys.unwrap_or_default();
+        foreign_keys.insert(column_name, foreign_table_name);
+
+        Self {
+            foreign_keys: Some(foreign_keys),
             ..self
         }
     }
 
     #[inline]
     pub fn set_read_only(&mut self, value: bool) {
-        self.read_only = value;
+        self.read_only = Some(value);
     }
 
     /// Fills any `None` properties with values from another TableConfig
@@ -273,35 +530,40 @@ impl<'a> TableOptions<'a> {
                 .clone()
                 .or_else(|| other.autogenerated_columns.clone()),
 
-            use_serde: self.use_serde || other.use_serde,
-            fns: self.fns || other.fns,
-            create_str_type: other.create_str_type,
-            update_str_type: other.update_str_type,
-            create_bytes_type: other.create_bytes_type,
-            update_bytes_type: other.update_bytes_type,
-            single_model_file: self.single_model_file || other.single_model_file,
-            read_only: self.read_only || other.read_only,
-        }
-    }
-}
-
-impl<'a> Default for TableOptions<'a> {
-    fn default() -> Self {
-        Self {
-            ignore: Default::default(),
-            autogenerated_columns: Default::default(),
-            #[cfg(feature = "tsync")]
-            tsync: Default::default(),
-            #[cfg(feature = "async")]
-            use_async: Default::default(),
-            use_serde: true,
-            fns: true,
-            create_str_type: Default::default(),
-            update_str_type: Default::default(),
-            create_bytes_type: Default::default(),
-            update_bytes_type: Default::default(),
-            single_model_file: false,
-            read_only: false,
+            use_serde: self.use_serde.or(other.use_serde),
+            fns: self.fns.or(other.fns),
+            create_str_type: self.create_str_type.or(other.create_str_type),
+            update_str_type: self.update_str_type.or(other.update_str_type),
+            create_bytes_type: self.create_bytes_type.or(other.create_bytes_type),
+            update_bytes_type: self.update_bytes_type.or(other.update_bytes_type),
+            single_model_file: self.single_model_file.or(other.single_model_file),
+            read_only: self.read_only.or(other.read_only),
+            column_types: self
+                .column_types
+                .clone()
+                .or_else(|| other.column_types.clone()),
+            foreign_keys: self
+                .foreign_keys
+                .clone()
+                .or_else(|| other.foreign_keys.clone()),
+            upsert: self.upsert.or(other.upsert),
+            upsert_conflict_columns: self
+                .upsert_conflict_columns
+                .clone()
+                .or_else(|| other.upsert_conflict_columns.clone()),
+            cursor_column: self.cursor_column.or(other.cursor_column),
+            singular_name: self.singular_name.or(other.singular_name),
+            soft_delete_column: self.soft_delete_column.or(other.soft_delete_column),
+            touch_on_update: self
+                .touch_on_update
+                .clone()
+                .or_else(|| other.touch_on_update.clone()),
+            immutable_columns: self
+                .immutable_columns
+                .clone()
+                .or_else(|| other.immutable_columns.clone()),
+            version_column: self.version_column.or(other.version_column),
+            tenant_column: self.tenant_column.or(other.tenant_column),
         }
     }
 }
@@ -328,6 +590,21 @@ pub struct GenerationConfigOpts<'a> {
     pub readonly_prefixes: Vec<String>,
     /// Suffixes to treat tables as readonly
     pub readonly_suffixes: Vec<String>,
+    /// Variants of postgres enums, keyed by the postgres type name
+    ///
+    /// For every type in the diesel schema's `sql_types` module that has a entry here,
+    /// a rust enum is generated in a "enums.rs" file and used for the columns of that type
+    pub enum_variants: HashMap<&'a str, Vec<&'a str>>,
+    /// Rust types to use for diesel sql types, keyed by the sql type name (case-insensitive)
+    ///
+    /// These take precedence over the builtin mapping, for example `Numeric = "rust_decimal::Decimal"`
+    pub type_overrides: HashMap<&'a str, &'a str>,
+    /// Rust type to use for postgres `Inet` & `Cidr` columns
+    pub network_address_type: NetworkAddressType,
+    /// How generated `create` & `update` functions get the affected row back
+    ///
+    /// If not set, [ReturningMode::Reread] is used for mysql and [ReturningMode::Returning] otherwise
+    pub returning_mode: Option<ReturningMode>,
 }
 
 impl GenerationConfigOpts<'_> {
@@ -363,6 +640,10 @@ impl Default for GenerationConfigOpts<'_> {
             once_connection_type: false,
             readonly_prefixes: Vec::default(),
             readonly_suffixes: Vec::default(),
+            enum_variants: HashMap::default(),
+            type_overrides: HashMap::default(),
+            network_address_type: NetworkAddressType::default(),
+            returning_mode: None,
         }
     }
 }
@@ -474,11 +755,113 @@ impl<'a> GenerationConfig<'a> {
         self.options.once_connection_type
     }
 
+    /// Get the configured variants for a postgres enum, by its postgres type name
+    #[inline]
+    pub fn get_enum_variants(&self, postgres_name: &str) -> Option<&[&str]> {
+        self.options
+            .enum_variants
+            .get(postgres_name)
+            .map(|v| v.as_slice())
+    }
+
+    /// Get the configured rust type for a diesel sql type (like `Timestamptz`), if any
+    #[inline]
+    pub fn get_type_override(&self, schema_type: &str) -> Option<&str> {
+        self.options
+            .type_overrides
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(schema_type))
+            .map(|(_, ty)| *ty)
+    }
+
+    #[inline]
+    pub fn get_network_address_type(&self) -> NetworkAddressType {
+        self.options.network_address_type
+    }
+
+    /// Get the connection type without a r2d2 pool wrapper and whitespace,
+    /// like `diesel::pg::PgConnection` for `diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>`
+    fn base_connection_type(&self) -> String {
+        let connection_type: String = self
+            .connection_type
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+
+        connection_type
+            .strip_prefix("diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<")
+            .and_then(|v| v.strip_suffix(">>"))
+            .map(str::to_string)
+            .unwrap_or(connection_type)
+    }
+
+    /// Get if the connection type (or backend) is a mysql one
+    ///
+    /// Only the diesel types are detected, custom connection types need a explicit [ReturningMode]
+    pub fn is_mysql(&self) -> bool {
+        #[cfg(feature = "advanced-queries")]
+        if self.diesel_backend.trim() == "diesel::mysql::Mysql" {
+            return true;
+        }
+
+        matches!(
+            self.base_connection_type().as_str(),
+            "diesel::mysql::MysqlConnection" | "diesel_async::AsyncMysqlConnection"
+        )
+    }
+
+    /// Get if the connection type (or backend) is a postgres one
+    ///
+    /// Only the diesel types are detected, like for [Self::is_mysql]
+    pub fn is_postgres(&self) -> bool {
+        #[cfg(feature = "advanced-queries")]
+        if self.diesel_backend.trim() == "diesel::pg::Pg" {
+            return true;
+        }
+
+        matches!(
+            self.base_connection_type().as_str(),
+            "diesel::pg::PgConnection" | "diesel_async::AsyncPgConnection"
+        )
+    }
+
+    /// Get if the connection type (or backend) is a sqlite one
+    ///
+    /// Only the diesel types are detected, like for [Self::is_mysql]
+    pub fn is_sqlite(&self) -> bool {
+        #[cfg(feature = "advanced-queries")]
+        if self.diesel_backend.trim() == "diesel::sqlite::Sqlite" {
+            return true;
+        }
+
+        self.base_connection_type() == "diesel::sqlite::SqliteConnection"
+    }
+
+    /// Get the configured [ReturningMode], or detect it from the connection type / backend
+    pub fn get_returning_mode(&self) -> ReturningMode {
+        if let Some(mode) = self.options.returning_mode {
+            return mode;
+        }
+
+        // mysql does not support "RETURNING"
+        if self.is_mysql() {
+            ReturningMode::Reread
+        } else {
+            ReturningMode::Returning
+        }
+    }
+
     #[inline]
     pub fn get_default_table_options(&self) -> &TableOptions<'_> {
         &self.options.default_table_options
     }
 
+    /// Get the options set for the table `name` itself, without the defaults applied
+    #[inline]
+    pub(crate) fn get_explicit_table_options(&self, name: &str) -> Option<&TableOptions<'_>> {
+        self.options.table_options.get(name)
+    }
+
     /// Get if any of the "once-*" options is active / if the common-file is active
     #[inline]
     pub fn any_once_option(&self) -> bool {
@@ -13,18 +13,20 @@
 //! default features: `tsync`, `backtrace`, `derive-queryablebyname`
 
 mod code;
+mod config_file;
 pub mod error;
 mod file;
 mod global;
 mod parser;
 
+pub use config_file::{ConfigFile, TableOptionsFile};
 pub use global::{
-    BytesType, GenerationConfig, GenerationConfigOpts, StringType, TableOptions,
-    DEFAULT_MODEL_PATH, DEFAULT_SCHEMA_PATH,
+    BytesType, GenerationConfig, GenerationConfigOpts, NetworkAddressType, ReturningMode,
+    StringType, TableOptions, DEFAULT_MODEL_PATH, DEFAULT_SCHEMA_PATH,
 };
 
 use error::IOErrorToError;
-pub use error::{Error, Result};
+pub use error::{Error, Result, SourceLocation};
 use file::MarkedFile;
 use heck::ToSnakeCase;
 use parser::ParsedTableMacro;
@@ -39,7 +41,7 @@ pub fn generate_code(
     diesel_schema_file_contents: &str,
     config: &GenerationConfig,
 ) -> Result<Vec<ParsedTableMacro>> {
-    parser::parse_and_generate_code(diesel_schema_file_contents, config)
+    parser::parse_and_generate_code(diesel_schema_file_contents, config).map(|v| v.tables)
 }
 
 /// Status indicating what happened to a file
@@ -74,6 +76,10 @@ pub struct FileChange {
     pub file: PathBuf,
     /// Status of the file
     pub status: FileChangeStatus,
+    /// Contents of the file before the change, empty if the file did not exist
+    pub old_contents: String,
+    /// Contents of the file after the change, empty if the file has been deleted
+    pub new_contents: String,
 }
 
 impl FileChange {
@@ -81,17 +87,55 @@ impl FileChange {
         Self {
             file: path.as_ref().to_owned(),
             status,
+            old_contents: String::new(),
+            new_contents: String::new(),
         }
     }
+
+    /// Create a [FileChange] for a [MarkedFile] that is (or would be) deleted
+    fn deleted(value: &MarkedFile) -> Self {
+        Self {
+            old_contents: value.get_original_contents().to_string(),
+            ..Self::new(&value.path, FileChangeStatus::Deleted)
+        }
+    }
+
+    /// Render a unified diff between the old and new contents of the file
+    ///
+    /// Returns a empty string if the contents did not change
+    pub fn unified_diff(&self) -> String {
+        let path = self.file.to_string_lossy();
+        let old_header = if self.old_contents.is_empty() {
+            "/dev/null".into()
+        } else {
+            format!("a/{path}")
+        };
+        let new_header = if self.status == FileChangeStatus::Deleted {
+            "/dev/null".into()
+        } else {
+            format!("b/{path}")
+        };
+
+        similar::TextDiff::from_lines(&self.old_contents, &self.new_contents)
+            .unified_diff()
+            .header(&old_header, &new_header)
+            .to_string()
+    }
 }
 
 // easily create a [FileChange] from a [MarkedFile]
 impl From<&MarkedFile> for FileChange {
     fn from(value: &MarkedFile) -> Self {
-        if value.is_modified() {
-            Self::new(&value.path, FileChangeStatus::Modified)
+        let status = if value.is_modified() {
+            FileChangeStatus::Modified
         } else {
-            Self::new(&value.path, FileChangeStatus::Unchanged)
+            FileChangeStatus::Unchanged
+        };
+
+        Self {
+            old_contents: value.get_original_contents().to_string(),
+            new_contents: value.get_file_contents().to_string(),
+            ..Self::new(&value.path, status)
         }
     }
 }
@@ -111,17 +155,43 @@ pub fn generate_files(
     input_diesel_schema_file: &Path,
     output_models_dir: &Path,
     config: GenerationConfig,
+) -> Result<Vec<FileChange>> {
+    generate_files_inner(input_diesel_schema_file, output_models_dir, config, false)
+}
+
+/// Generate all Models for a given diesel schema file, without saving anything to disk
+///
+/// The returned [FileChange]s describe what [generate_files] would do,
+/// which can be used to check if the models are up-to-date with the schema
+pub fn check_files(
+    input_diesel_schema_file: &Path,
+    output_models_dir: &Path,
+    config: GenerationConfig,
+) -> Result<Vec<FileChange>> {
+    generate_files_inner(input_diesel_schema_file, output_models_dir, config, true)
+}
+
+/// Actual implementation of [generate_files] and [check_files]
+///
+/// if `dry_run` is `true`, no changes are written to disk
+fn generate_files_inner(
+    input_diesel_schema_file: &Path,
+    output_models_dir: &Path,
+    config: GenerationConfig,
+    dry_run: bool,
 ) -> Result<Vec<FileChange>> {
     global::validate_config(&config)?;
 
-    let generated = generate_code(
-        &std::fs::read_to_string(input_diesel_schema_file)
-            .attach_path_err(input_diesel_schema_file)?,
-        &config,
-    )?;
+    let schema_file_contents = std::fs::read_to_string(input_diesel_schema_file)
+        .attach_path_err(input_diesel_schema_file)?;
+    let parsed_schema = parser::parse_and_generate_code(&schema_file_contents, &config)
+        .map_err(|err| err.attach_source(input_diesel_schema_file, &schema_file_contents))?;
+    let generated = parsed_schema.tables;
 
     if !output_models_dir.exists() {
-        std::fs::create_dir(output_models_dir).attach_path_err(output_models_dir)?;
+        if !dry_run {
+            std::fs::create_dir(output_models_dir).attach_path_err(output_models_dir)?;
+        }
     } else if !output_models_dir.is_dir() {
         return Err(Error::not_a_directory(
             "Expected output argument to be a directory or non-existent.",
@@ -156,31 +226,241 @@ pub fn generate_files(
 
             tmp
         });
-        common_file.write()?;
+        if !dry_run {
+            common_file.write()?;
+        }
         file_changes.push(FileChange::from(&common_file));
 
         mod_rs.ensure_mod_stmt("common");
     }
 
-    // pass 1: add code for new tables
+    let enums_rs_path = output_models_dir.join("enums.rs");
+    if let Some(enums_code) = code::generate_enums(&parsed_schema.sql_types, &config) {
+        let mut enums_file = MarkedFile::new(enums_rs_path)?;
+        enums_file.ensure_file_signature()?;
+        enums_file.change_file_contents(enums_code);
+        if !dry_run {
+            enums_file.write()?;
+        }
+        file_changes.push(FileChange::from(&enums_file));
+
+        mod_rs.ensure_mod_stmt("enums");
+    } else if enums_rs_path.is_file() {
+        // remove the enums file if there are no enums anymore, but only if it was generated
+        let enums_file = MarkedFile::new(enums_rs_path)?;
+        if enums_file.has_file_signature() {
+            file_changes.push(FileChange::deleted(&enums_file));
+            if !dry_run {
+                enums_file.delete()?;
+            }
+
+            mod_rs.remove_mod_stmt("enums");
+        }
+    }
+
+    // generate the tables, grouped by the schema module they are in
+    // tables from a postgres schema (like "pub mod tenant { }") are generated into a sub-directory of the same name
+    let mut schema_modules: Vec<Option<&syn::Ident>> = vec![None];
     for table in generated.iter() {
-        if config.get_once_common_structs() && table.name == "common" {
-            return Err(Error::other("Cannot have a table named \"common\" while having option \"once_common_structs\" enabled"));
+        if table.schema_module.is_none() {
+            if config.get_once_common_structs() && table.name == "common" {
+                return Err(Error::other("Cannot have a table named \"common\" while having option \"once_common_structs\" enabled"));
+            }
+            if mod_rs.has_mod_stmt("enums") && table.name == "enums" {
+                return Err(Error::other(
+                    "Cannot have a table named \"enums\" while having postgres enums generated",
+                ));
+            }
+        }
+
+        if !schema_modules.contains(&table.schema_module.as_ref()) {
+            schema_modules.push(table.schema_module.as_ref());
+        }
+    }
+
+    let schema_module_names = schema_modules
+        .iter()
+        .flatten()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+
+    for schema_module in schema_modules {
+        let tables = generated
+            .iter()
+            .filter(|t| t.schema_module.as_ref() == schema_module)
+            .collect::<Vec<_>>();
+
+        let Some(schema_module) = schema_module else {
+            generate_table_files(
+                &tables,
+                output_models_dir,
+                &mut mod_rs,
+                &config,
+                dry_run,
+                &mut file_changes,
+            )?;
+            continue;
+        };
+
+        let schema_module = schema_module.to_string();
+        let schema_dir = output_models_dir.join(&schema_module);
+
+        if !schema_dir.exists() {
+            if !dry_run {
+                std::fs::create_dir(&schema_dir).attach_path_err(&schema_dir)?;
+            }
+        } else if !schema_dir.is_dir() {
+            return Err(Error::not_a_directory("Expected a directory", schema_dir));
+        }
+
+        let mut schema_mod_rs = MarkedFile::new(schema_dir.join("mod.rs"))?;
+        generate_table_files(
+            &tables,
+            &schema_dir,
+            &mut schema_mod_rs,
+            &config,
+            dry_run,
+            &mut file_changes,
+        )?;
+        if !dry_run {
+            schema_mod_rs.write()?;
         }
+        file_changes.push(FileChange::from(&schema_mod_rs));
+
+        mod_rs.ensure_mod_stmt(&schema_module);
+    }
+
+    delete_stale_schema_modules(
+        output_models_dir,
+        &schema_module_names,
+        &mut mod_rs,
+        &config,
+        dry_run,
+        &mut file_changes,
+    )?;
+
+    if !dry_run {
+        mod_rs.write()?;
+    }
+    file_changes.push(FileChange::from(&mod_rs));
+
+    Ok(file_changes)
+}
+
+/// Delete the directories of schema modules (like "models/tenant/") that do not exist in the schema anymore
+///
+/// A directory is considered a generated schema module if it has no "generated.rs" itself, but contains generated table directories
+fn delete_stale_schema_modules(
+    models_dir: &Path,
+    schema_module_names: &[String],
+    mod_rs: &mut MarkedFile,
+    config: &GenerationConfig,
+    dry_run: bool,
+    file_changes: &mut Vec<FileChange>,
+) -> Result<()> {
+    // when doing a dry-run, the output directory may not exist yet, so there is nothing to delete
+    if !models_dir.exists() {
+        return Ok(());
+    }
+
+    for item in std::fs::read_dir(models_dir).attach_path_err(models_dir)? {
+        let item = item.attach_path_err(models_dir)?;
+        let schema_dir = item.path();
+        let Some(module_name) = item.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+
+        if !schema_dir.is_dir()
+            || schema_module_names.contains(&module_name)
+            || !is_generated_schema_module_dir(&schema_dir)?
+        {
+            continue;
+        }
+
+        // delete all generated tables of the schema module
+        let mut schema_mod_rs = MarkedFile::new(schema_dir.join("mod.rs"))?;
+        generate_table_files(
+            &[],
+            &schema_dir,
+            &mut schema_mod_rs,
+            config,
+            dry_run,
+            file_changes,
+        )?;
+
+        if schema_mod_rs.get_file_contents().trim().is_empty() {
+            file_changes.push(FileChange::deleted(&schema_mod_rs));
+            if !dry_run {
+                schema_mod_rs.delete()?;
+            }
+        } else {
+            if !dry_run {
+                schema_mod_rs.write()?;
+            }
+            file_changes.push(FileChange::from(&schema_mod_rs));
+        }
+
+        // delete the schema dir if there's nothing else in there
+        let is_empty = !dry_run
+            && schema_dir
+                .read_dir()
+                .attach_path_err(&schema_dir)?
+                .next()
+                .is_none();
+        if is_empty {
+            std::fs::remove_dir(&schema_dir).attach_path_err(&schema_dir)?;
+        }
+
+        mod_rs.remove_mod_stmt(&module_name);
+    }
+
+    Ok(())
+}
+
+/// Check if `dir` is a generated schema module directory (has a "mod.rs" and generated table directories, but no "generated.rs" itself)
+fn is_generated_schema_module_dir(dir: &Path) -> Result<bool> {
+    if !dir.join("mod.rs").is_file() || dir.join("generated.rs").exists() {
+        return Ok(false);
+    }
+
+    for item in std::fs::read_dir(dir).attach_path_err(dir)? {
+        let generated_rs_path = item.attach_path_err(dir)?.path().join("generated.rs");
+
+        if generated_rs_path.is_file() && MarkedFile::new(generated_rs_path)?.has_file_signature() {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Generate the files for all given tables into `models_dir` and delete the files of tables that do not exist anymore
+///
+/// `mod_rs` is the "mod.rs" file of `models_dir`, it is not written by this function
+fn generate_table_files(
+    tables: &[&ParsedTableMacro],
+    models_dir: &Path,
+    mod_rs: &mut MarkedFile,
+    config: &GenerationConfig,
+    dry_run: bool,
+    file_changes: &mut Vec<FileChange>,
+) -> Result<()> {
+    // pass 1: add code for new tables
+    for table in tables.iter() {
         let table_name = table.name.to_string();
         let table_filename = get_table_module_name(&table_name);
         let table_config = config.table(&table_name);
         let table_dir = if table_config.get_single_model_file() {
-            output_models_dir.to_owned()
+            models_dir.to_owned()
         } else {
-            output_models_dir.join(&table_filename)
+            models_dir.join(&table_filename)
         };
 
         if !table_dir.exists() {
-            std::fs::create_dir(&table_dir).attach_path_err(&table_dir)?;
-        }
-
-        if !table_dir.is_dir() {
+            if !dry_run {
+                std::fs::create_dir(&table_dir).attach_path_err(&table_dir)?;
+            }
+        } else if !table_dir.is_dir() {
             return Err(Error::not_a_directory("Expected a directory", table_dir));
         }
 
@@ -197,14 +477,18 @@ pub fn generate_files(
 
         table_generated_rs.ensure_file_signature()?;
         table_generated_rs.change_file_contents(table.generated_code.clone());
-        table_generated_rs.write()?;
+        if !dry_run {
+            table_generated_rs.write()?;
+        }
 
         file_changes.push(FileChange::from(&table_generated_rs));
 
         if !table_config.get_single_model_file() {
             table_mod_rs.ensure_mod_stmt("generated");
             table_mod_rs.ensure_use_stmt("generated::*");
-            table_mod_rs.write()?;
+            if !dry_run {
+                table_mod_rs.write()?;
+            }
             file_changes.push(FileChange::from(&table_mod_rs));
         }
 
@@ -212,9 +496,18 @@ pub fn generate_files(
     }
 
     // pass 2: delete code for removed tables
-    for item in std::fs::read_dir(output_models_dir).attach_path_err(output_models_dir)? {
+    // when doing a dry-run, the output directory may not exist yet, so there is nothing to delete
+    let existing_items = if models_dir.exists() {
+        std::fs::read_dir(models_dir)
+            .attach_path_err(models_dir)?
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    for item in existing_items {
         // TODO: this does not work with "single-model-file"
-        let item = item.attach_path_err(output_models_dir)?;
+        let item = item.attach_path_err(models_dir)?;
 
         // check if item is a directory
         let file_type = item
@@ -226,10 +519,11 @@ pub fn generate_files(
 
         // check if it's a generated file
         let generated_rs_path = item.path().join("generated.rs");
-        if !generated_rs_path.exists()
-            || !generated_rs_path.is_file()
-            || !MarkedFile::new(generated_rs_path.clone())?.has_file_signature()
-        {
+        if !generated_rs_path.exists() || !generated_rs_path.is_file() {
+            continue;
+        }
+        let generated_rs = MarkedFile::new(generated_rs_path)?;
+        if !generated_rs.has_file_signature() {
             continue;
         }
 
@@ -239,7 +533,7 @@ pub fn generate_files(
             "Could not determine name of file '{:#?}'",
             item.path()
         )))?;
-        let found = generated.iter().find(|g| {
+        let found = tables.iter().find(|g| {
             get_table_module_name(&g.name.to_string()).eq_ignore_ascii_case(associated_table_name)
         });
         if found.is_some() {
@@ -247,11 +541,10 @@ pub fn generate_files(
         }
 
         // this table was deleted, let's delete the generated code
-        std::fs::remove_file(&generated_rs_path).attach_path_err(&generated_rs_path)?;
-        file_changes.push(FileChange::new(
-            &generated_rs_path,
-            FileChangeStatus::Deleted,
-        ));
+        file_changes.push(FileChange::deleted(&generated_rs));
+        if !dry_run {
+            generated_rs.delete()?;
+        }
 
         // remove the mod.rs file if there isn't anything left in there except the use stmt
         let table_mod_rs_path = item.path().join("mod.rs");
@@ -260,24 +553,28 @@ pub fn generate_files(
 
             table_mod_rs.remove_mod_stmt("generated");
             table_mod_rs.remove_use_stmt("generated::*");
-            table_mod_rs.write()?;
 
             if table_mod_rs.get_file_contents().trim().is_empty() {
-                let table_mod_rs = table_mod_rs.delete()?;
-                file_changes.push(FileChange::new(table_mod_rs, FileChangeStatus::Deleted));
+                file_changes.push(FileChange::deleted(&table_mod_rs));
+                if !dry_run {
+                    table_mod_rs.delete()?;
+                }
             } else {
-                table_mod_rs.write()?; // write the changes we made above
+                if !dry_run {
+                    table_mod_rs.write()?; // write the changes we made above
+                }
                 file_changes.push(FileChange::from(&table_mod_rs));
             }
         }
 
         // delete the table dir if there's nothing else in there
-        let is_empty = item
-            .path()
-            .read_dir()
-            .attach_path_err(item.path())?
-            .next()
-            .is_none();
+        let is_empty = !dry_run
+            && item
+                .path()
+                .read_dir()
+                .attach_path_err(item.path())?
+                .next()
+                .is_none();
         if is_empty {
             std::fs::remove_dir(item.path()).attach_path_err(item.path())?;
         }
@@ -286,8 +583,5 @@ pub fn generate_files(
         mod_rs.remove_mod_stmt(associated_table_name);
     }
 
-    mod_rs.write()?;
-    file_changes.push(FileChange::from(&mod_rs));
-
-    Ok(file_changes)
+    Ok(())
 }
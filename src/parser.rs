@@ -1,8 +1,10 @@
 use heck::ToPascalCase;
+use syn::spanned::Spanned;
 use syn::Ident;
 use syn::Item::Macro;
 
-use crate::{code, Error, GenerationConfig, Result};
+use crate::error::ErrorEnum;
+use crate::{code, Error, GenerationConfig, Result, SourceLocation};
 
 /// dsync file signature for generated & managed files
 pub const FILE_SIGNATURE: &str = "/* @generated and managed by dsync */";
@@ -14,10 +16,14 @@ pub const FILE_SIGNATURE: &str = "/* @generated and managed by dsync */";
 pub struct ParsedColumnMacro {
     /// Rust type to use (like `String`)
     pub ty: String,
+    /// Diesel sql type of the column, without any wrappers (like `Int4` for `Nullable<Unsigned<Int4>>`)
+    pub sql_type: String,
     /// Rust ident for the field name
     pub name: Ident,
     /// Actual column name, as parsed from the attributes, or the same as "name"
     pub column_name: String,
+    /// Doc comment lines of the column (`#[doc = "..."]` / `///`), without the leading `///`
+    pub doc_comments: Vec<String>,
     pub is_nullable: bool,
     pub is_unsigned: bool,
     pub is_array: bool,
@@ -28,8 +34,14 @@ pub struct ParsedColumnMacro {
 pub struct ParsedTableMacro {
     /// Name of the table
     pub name: Ident,
+    /// Name of the module the table is defined in, if not defined at the top-level
+    ///
+    /// Diesel puts tables of a postgres schema into a module (like `pub mod tenant { }`)
+    pub schema_module: Option<Ident>,
     /// Rust struct name to use
     pub struct_name: String,
+    /// Doc comment lines of the table (`#[doc = "..."]` / `///`), without the leading `///`
+    pub doc_comments: Vec<String>,
     /// All parsed columns
     pub columns: Vec<ParsedColumnMacro>,
     /// All Primary key column idents
@@ -44,20 +56,122 @@ pub struct ParsedTableMacro {
 }
 
 impl ParsedTableMacro {
+    /// Get the diesel schema import path for this table, including the schema module if any
+    ///
+    /// Example: `crate::schema::tenant::`
+    pub fn schema_path(&self, config: &GenerationConfig) -> String {
+        match &self.schema_module {
+            Some(module) => format!("{}{module}::", config.get_schema_path()),
+            None => config.get_schema_path().to_string(),
+        }
+    }
+
+    /// Get the dsync model import path for this table's schema module
+    ///
+    /// Example: `crate::models::tenant::`
+    pub fn model_path(&self, config: &GenerationConfig) -> String {
+        match &self.schema_module {
+            Some(module) => format!("{}{module}::", config.get_model_path()),
+            None => config.get_model_path().to_string(),
+        }
+    }
+
     pub fn primary_key_column_names(&self) -> Vec<String> {
         self.primary_key_columns
             .iter()
             .map(|i| i.to_string())
             .collect()
     }
+
+    /// Get the foreign keys which are used as the diesel association (`belongs_to`) to their parent table
+    ///
+    /// diesel only allows one `belongs_to` per parent table, so if multiple foreign keys reference the same
+    /// parent table, the first declared one is the association
+    pub fn primary_associations(&self) -> Vec<&(ForeignTableName, JoinColumn)> {
+        self.foreign_keys
+            .iter()
+            .enumerate()
+            .filter(|(idx, fk)| !self.foreign_keys[..*idx].iter().any(|v| v.0 == fk.0))
+            .map(|(_, fk)| fk)
+            .collect()
+    }
+
+    /// Get the foreign keys which reference a parent table that is already used by another foreign key,
+    /// see [`Self::primary_associations`]
+    pub fn secondary_associations(&self) -> Vec<&(ForeignTableName, JoinColumn)> {
+        self.foreign_keys
+            .iter()
+            .enumerate()
+            .filter(|(idx, fk)| self.foreign_keys[..*idx].iter().any(|v| v.0 == fk.0))
+            .map(|(_, fk)| fk)
+            .collect()
+    }
 }
 
 type ForeignTableName = Ident;
 type JoinColumn = String;
 
+/// Parsed representation of a struct in the `sql_types` module of a diesel schema
+///
+/// ```rs
+/// pub mod sql_types {
+///     #[derive(diesel::sql_types::SqlType)]
+///     #[diesel(postgres_type(name = "status"))]
+///     pub struct Status;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParsedSqlType {
+    /// Rust ident of the sql type (like `Status`)
+    pub name: Ident,
+    /// Name of the type in postgres (like `status`)
+    pub postgres_name: String,
+    /// Name of the module the `sql_types` module is defined in, if not defined at the top-level
+    pub schema_module: Option<Ident>,
+}
+
+impl ParsedSqlType {
+    /// Get the full path to the diesel sql type
+    ///
+    /// Example: `crate::schema::tenant::sql_types::Status`
+    pub fn sql_type_path(&self, config: &GenerationConfig) -> String {
+        match &self.schema_module {
+            Some(module) => format!(
+                "{}{module}::sql_types::{}",
+                config.get_schema_path(),
+                self.name
+            ),
+            None => format!("{}sql_types::{}", config.get_schema_path(), self.name),
+        }
+    }
+
+    /// Get the full path to the generated rust enum
+    ///
+    /// Enums of a schema module are generated in a module of the same name, so equally named enums of different schemas do not clash
+    ///
+    /// Example: `crate::models::enums::tenant::Status`
+    pub fn enum_path(&self, config: &GenerationConfig) -> String {
+        match &self.schema_module {
+            Some(module) => format!("{}enums::{module}::{}", config.get_model_path(), self.name),
+            None => format!("{}enums::{}", config.get_model_path(), self.name),
+        }
+    }
+}
+
+/// Everything that was parsed (and generated) from a diesel schema file
+#[derive(Debug, Clone)]
+pub struct ParsedSchema {
+    /// All parsed and not ignored tables
+    pub tables: Vec<ParsedTableMacro>,
+    /// All custom sql types, from the `sql_types` module
+    pub sql_types: Vec<ParsedSqlType>,
+}
+
 /// Parsed representation of the `diesel::joinable!` macro
 #[derive(Debug, Clone)]
 pub struct ParsedJoinMacro {
+    /// Name of the module the macro is defined in, if not defined at the top-level
+    pub schema_module: Option<Ident>,
     /// Table ident with the foreign key
     pub table1: Ident,
     /// Table ident for the foreign key
@@ -70,64 +184,272 @@ pub struct ParsedJoinMacro {
 pub fn parse_and_generate_code(
     schema_file_contents: &str,
     config: &GenerationConfig,
-) -> Result<Vec<ParsedTableMacro>> {
-    let schema_file = syn::parse_file(schema_file_contents).unwrap();
+) -> Result<ParsedSchema> {
+    let schema_file = syn::parse_file(schema_file_contents).map_err(|err| {
+        Error::unsupported_schema_format(format!("Could not parse schema file: {err}"))
+            .with_location(SourceLocation::from(err.span()))
+    })?;
 
-    let mut tables: Vec<ParsedTableMacro> = vec![];
+    // flatten all items, including the ones in inline modules (like "pub mod tenant { }" for postgres schemas)
+    let mut items: Vec<(Option<Ident>, syn::Item)> = vec![];
 
     for item in schema_file.items {
+        match item {
+            syn::Item::Mod(syn::ItemMod {
+                ident,
+                content: Some((_, mod_items)),
+                ..
+            }) if ident != "sql_types" => {
+                items.extend(mod_items.into_iter().map(|v| (Some(ident.clone()), v)));
+            }
+            _ => items.push((None, item)),
+        }
+    }
+
+    // the sql types need to be known before parsing any table, because columns may use them
+    let mut sql_types: Vec<ParsedSqlType> = vec![];
+
+    for (schema_module, item) in items.iter() {
+        if let syn::Item::Mod(mod_item) = item {
+            if mod_item.ident == "sql_types" {
+                sql_types.extend(handle_sql_types_mod(mod_item, schema_module.as_ref())?);
+            }
+        }
+    }
+
+    let mut tables: Vec<ParsedTableMacro> = vec![];
+    // names of tables that exist in the schema, but are ignored (schema_module, table_name)
+    let mut ignored_tables: Vec<(Option<Ident>, String)> = vec![];
+    // joins are resolved after all tables are known, because "joinable!" may appear before the "table!" it references
+    let mut joins: Vec<ParsedJoinMacro> = vec![];
+
+    for (schema_module, item) in items {
         if let Macro(macro_item) = item {
             let macro_identifier = macro_item
                 .mac
                 .path
                 .segments
                 .last()
-                .ok_or(Error::other("could not read identifier for macro"))?
+                .ok_or(
+                    Error::other("could not read identifier for macro")
+                        .with_location(SourceLocation::from(macro_item.mac.path.span())),
+                )?
                 .ident
                 .to_string();
 
             match macro_identifier.as_str() {
                 "table" => {
-                    let parsed_table = handle_table_macro(macro_item, config)?;
+                    let parsed_table =
+                        handle_table_macro(macro_item, schema_module, config, &sql_types)?;
 
                     // make sure the table isn't ignored
                     let table_options = config.table(parsed_table.name.to_string().as_str());
                     if !table_options.get_ignore() {
                         tables.push(parsed_table);
+                    } else {
+                        ignored_tables
+                            .push((parsed_table.schema_module, parsed_table.name.to_string()));
                     }
                 }
                 "joinable" => {
-                    let parsed_join = handle_joinable_macro(macro_item)?;
-
-                    for table in tables.iter_mut() {
-                        if parsed_join
-                            .table1
-                            .to_string()
-                            .eq(table.name.to_string().as_str())
-                        {
-                            table.foreign_keys.push((
-                                parsed_join.table2.clone(),
-                                parsed_join.table1_columns.clone(),
-                            ));
-                            break;
-                        }
-                    }
+                    joins.push(handle_joinable_macro(macro_item, schema_module)?);
                 }
                 _ => {}
             };
         }
     }
 
+    for join in joins {
+        resolve_join(&mut tables, &ignored_tables, join)?;
+    }
+
+    resolve_configured_foreign_keys(&mut tables, &ignored_tables, config)?;
+
     for table in tables.iter_mut() {
-        table.generated_code = code::generate_for_table(table, config);
+        table.generated_code = code::generate_for_table(table, config)?;
     }
 
-    Ok(tables)
+    Ok(ParsedSchema { tables, sql_types })
 }
 
-fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro> {
+/// Parse all sql types from the `pub mod sql_types { }` module
+///
+/// Only postgres types (`#[diesel(postgres_type(name = "..."))]`) are currently supported, other types are ignored
+fn handle_sql_types_mod(
+    mod_item: &syn::ItemMod,
+    schema_module: Option<&Ident>,
+) -> Result<Vec<ParsedSqlType>> {
+    let mut sql_types = vec![];
+
+    let Some((_, items)) = &mod_item.content else {
+        return Ok(sql_types);
+    };
+
+    for item in items {
+        let syn::Item::Struct(struct_item) = item else {
+            continue;
+        };
+
+        let mut postgres_name: Option<String> = None;
+
+        for attr in struct_item.attrs.iter() {
+            if !attr.path().is_ident("diesel") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("postgres_type") {
+                    meta.parse_nested_meta(|inner| {
+                        let value: syn::LitStr = inner.value()?.parse()?;
+                        if inner.path.is_ident("name") {
+                            postgres_name = Some(value.value());
+                        }
+
+                        Ok(())
+                    })
+                } else {
+                    // consume and ignore the values of other attributes (like "mysql_type")
+                    if !meta.input.is_empty() && !meta.input.peek(syn::Token![,]) {
+                        meta.input.parse::<proc_macro2::TokenStream>()?;
+                    }
+
+                    Ok(())
+                }
+            })
+            .map_err(|err| {
+                Error::unsupported_schema_format(format!(
+                    "Could not parse attribute of sql type \"{}\": {}",
+                    struct_item.ident, err
+                ))
+                .with_location(SourceLocation::from(err.span()))
+            })?;
+        }
+
+        if let Some(postgres_name) = postgres_name {
+            sql_types.push(ParsedSqlType {
+                name: struct_item.ident.clone(),
+                postgres_name,
+                schema_module: schema_module.cloned(),
+            });
+        }
+    }
+
+    Ok(sql_types)
+}
+
+/// Attach a parsed `diesel::joinable!` macro to the table it belongs to
+///
+/// Joins which involve a ignored table are skipped, joins which reference a table not in the schema are a error
+fn resolve_join(
+    tables: &mut [ParsedTableMacro],
+    ignored_tables: &[(Option<Ident>, String)],
+    join: ParsedJoinMacro,
+) -> Result<()> {
+    for join_table_ident in [&join.table1, &join.table2] {
+        let join_table = join_table_ident.to_string();
+
+        if ignored_tables
+            .iter()
+            .any(|(module, name)| module == &join.schema_module && name == &join_table)
+        {
+            return Ok(());
+        }
+
+        if !tables
+            .iter()
+            .any(|t| t.schema_module == join.schema_module && t.name == join_table)
+        {
+            return Err(Error::unsupported_schema_format(format!(
+                "joinable!({} -> {} ({})) references table \"{}\", which does not exist in the schema",
+                join.table1, join.table2, join.table1_columns, join_table
+            ))
+            .with_location(SourceLocation::from(join_table_ident.span()).table(join_table)));
+        }
+    }
+
+    let table = tables
+        .iter_mut()
+        .find(|t| t.schema_module == join.schema_module && t.name == join.table1)
+        .expect("table to exist, because it was checked above");
+
+    table.foreign_keys.push((join.table2, join.table1_columns));
+
+    Ok(())
+}
+
+/// Attach the foreign keys from the `foreign_keys` table option, after the ones from `diesel::joinable!`
+///
+/// Foreign keys to a ignored table are skipped, foreign keys of columns or to tables not in the schema are a error
+fn resolve_configured_foreign_keys(
+    tables: &mut [ParsedTableMacro],
+    ignored_tables: &[(Option<Ident>, String)],
+    config: &GenerationConfig,
+) -> Result<()> {
+    for idx in 0..tables.len() {
+        let table_name = tables[idx].name.to_string();
+        let table_options = config.table(&table_name);
+
+        for column_name in table_options.get_foreign_key_columns() {
+            if !tables[idx].columns.iter().any(|c| c.name == column_name) {
+                return Err(Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                    "foreign key \"{table_name}.{column_name}\" references column \"{column_name}\", which does not exist in table \"{table_name}\""
+                ))));
+            }
+        }
+
+        // in column order, so that the first foreign key to a parent table (the diesel association) is stable
+        let foreign_keys: Vec<(String, String)> = tables[idx]
+            .columns
+            .iter()
+            .filter_map(|c| {
+                let column_name = c.name.to_string();
+                table_options
+                    .get_foreign_key(&column_name)
+                    .map(|foreign_table_name| (column_name, foreign_table_name.to_string()))
+            })
+            .collect();
+
+        for (column_name, foreign_table_name) in foreign_keys {
+            let schema_module = &tables[idx].schema_module;
+
+            if ignored_tables
+                .iter()
+                .any(|(module, name)| module == schema_module && name == &foreign_table_name)
+            {
+                continue;
+            }
+
+            let foreign_table = tables
+                .iter()
+                .find(|t| &t.schema_module == schema_module && t.name == foreign_table_name)
+                .map(|t| t.name.clone())
+                .ok_or_else(|| {
+                    Error::new(ErrorEnum::InvalidGenerationConfig(format!(
+                        "foreign key \"{table_name}.{column_name}\" references table \"{foreign_table_name}\", which does not exist in the schema"
+                    )))
+                })?;
+
+            let table = &mut tables[idx];
+
+            // already declared with "joinable!"
+            if table.foreign_keys.iter().any(|(_, c)| c == &column_name) {
+                continue;
+            }
+
+            table.foreign_keys.push((foreign_table, column_name));
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_joinable_macro(
+    macro_item: syn::ItemMacro,
+    schema_module: Option<Ident>,
+) -> Result<ParsedJoinMacro> {
     // println!("joinable! macro: {:#?}", macro_item);
 
+    let macro_location = SourceLocation::from(macro_item.mac.path.span());
     let mut table1_name: Option<Ident> = None;
     let mut table2_name: Option<Ident> = None;
     let mut table2_join_column: Option<String> = None;
@@ -145,7 +467,8 @@ fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro>
                 if table1_name.is_none() || table2_name.is_none() {
                     return Err(Error::unsupported_schema_format(
                         "encountered join column group too early",
-                    ));
+                    )
+                    .with_location(SourceLocation::from(group.span())));
                 } else {
                     table2_join_column = Some(group.stream().to_string());
                 }
@@ -155,22 +478,28 @@ fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro>
     }
 
     Ok(ParsedJoinMacro {
-        table1: table1_name.ok_or(Error::unsupported_schema_format(
-            "could not determine first join table name",
-        ))?,
-        table2: table2_name.ok_or(Error::unsupported_schema_format(
-            "could not determine second join table name",
-        ))?,
-        table1_columns: table2_join_column.ok_or(Error::unsupported_schema_format(
-            "could not determine join column name",
-        ))?,
+        schema_module,
+        table1: table1_name.ok_or(
+            Error::unsupported_schema_format("could not determine first join table name")
+                .with_location(macro_location.clone()),
+        )?,
+        table2: table2_name.ok_or(
+            Error::unsupported_schema_format("could not determine second join table name")
+                .with_location(macro_location.clone()),
+        )?,
+        table1_columns: table2_join_column.ok_or(
+            Error::unsupported_schema_format("could not determine join column name")
+                .with_location(macro_location),
+        )?,
     })
 }
 
 /// Try to parse a `diesel::table!` macro
 fn handle_table_macro(
     macro_item: syn::ItemMacro,
+    schema_module: Option<Ident>,
     config: &GenerationConfig,
+    sql_types: &[ParsedSqlType],
 ) -> Result<ParsedTableMacro> {
     let mut table_name_ident: Option<Ident> = None;
     let mut table_primary_key_idents: Vec<Ident> = vec![];
@@ -178,6 +507,17 @@ fn handle_table_macro(
 
     let mut skip_until_semicolon = false;
     let mut skip_square_brackets = false;
+    let mut table_doc_comments: Vec<String> = vec![];
+    let macro_location = SourceLocation::from(macro_item.mac.path.span());
+
+    // location of a token inside the table macro, with the table name attached if already known
+    let location = |span: proc_macro2::Span, table_name: &Option<Ident>| {
+        let location = SourceLocation::from(span);
+        match table_name {
+            Some(name) => location.table(name),
+            None => location,
+        }
+    };
 
     for item in macro_item.mac.tokens.into_iter() {
         if skip_until_semicolon {
@@ -210,6 +550,12 @@ fn handle_table_macro(
                 if skip_square_brackets {
                     if group.delimiter() == proc_macro2::Delimiter::Bracket {
                         skip_square_brackets = false;
+
+                        if let Some((name, value)) = parse_diesel_attr_group(&group) {
+                            if name == "doc" {
+                                table_doc_comments.extend(split_doc_comment(&value));
+                            }
+                        }
                     }
                     continue;
                 }
@@ -225,11 +571,18 @@ fn handle_table_macro(
                 } else if group.delimiter() == proc_macro2::Delimiter::Brace {
                     // columns group
                     // println!("GROUP-cols {:#?}", group);
+                    let table_options = config.table(
+                        &table_name_ident
+                            .as_ref()
+                            .map(|v| v.to_string())
+                            .unwrap_or_default(),
+                    );
 
                     // rust name parsed from the macro (the "HERE" in "HERE -> TYPE")
                     let mut rust_column_name: Option<Ident> = None;
                     // actual column name, parsed from the attribute value, if any ("#[sql_name = "test"]")
                     let mut actual_column_name: Option<String> = None;
+                    let mut column_doc_comments: Vec<String> = vec![];
                     let mut column_type: Option<Ident> = None;
                     let mut column_nullable: bool = false;
                     let mut column_unsigned: bool = false;
@@ -249,6 +602,8 @@ fn handle_table_macro(
                                     if let Some((name, value)) = parse_diesel_attr_group(&group) {
                                         if name == "sql_name" {
                                             actual_column_name = Some(value);
+                                        } else if name == "doc" {
+                                            column_doc_comments.extend(split_doc_comment(&value));
                                         }
                                     }
                                 }
@@ -315,26 +670,55 @@ fn handle_table_macro(
                                     let rust_column_name_checked = rust_column_name.ok_or(
                                         Error::unsupported_schema_format(
                                             "Invalid column name syntax",
-                                        ),
+                                        )
+                                        .with_location(location(punct.span(), &table_name_ident)),
                                     )?;
                                     let column_name = actual_column_name
                                         .unwrap_or(rust_column_name_checked.to_string());
+                                    let column_type_checked = column_type.ok_or(
+                                        Error::unsupported_schema_format(
+                                            "Invalid column type syntax",
+                                        )
+                                        .with_location(
+                                            location(punct.span(), &table_name_ident)
+                                                .column_name(&column_name),
+                                        ),
+                                    )?;
+
+                                    // a rust type configured for the column takes precedence over the sql type
+                                    let column_type_override = table_options
+                                        .get_column_type(&rust_column_name_checked.to_string());
+                                    let ty =
+                                        match column_type_override {
+                                            Some(ty) => ty.to_string(),
+                                            None => schema_type_to_rust_type(
+                                                column_type_checked.to_string(),
+                                                column_unsigned,
+                                                schema_module.as_ref(),
+                                                config,
+                                                sql_types,
+                                            )
+                                            .map_err(|err| {
+                                                err.with_location(
+                                                    location(
+                                                        column_type_checked.span(),
+                                                        &table_name_ident,
+                                                    )
+                                                    .column_name(&column_name),
+                                                )
+                                            })?,
+                                        };
 
                                     // add the column
                                     table_columns.push(ParsedColumnMacro {
+                                        ty,
+                                        sql_type: column_type_checked.to_string(),
                                         name: rust_column_name_checked,
-                                        ty: schema_type_to_rust_type(
-                                            column_type
-                                                .ok_or(Error::unsupported_schema_format(
-                                                    "Invalid column type syntax",
-                                                ))?
-                                                .to_string(),
-                                            config,
-                                        )?,
                                         is_nullable: column_nullable,
                                         is_unsigned: column_unsigned,
                                         is_array: column_array,
                                         column_name,
+                                        doc_comments: std::mem::take(&mut column_doc_comments),
                                     });
 
                                     // reset the properties
@@ -346,10 +730,11 @@ fn handle_table_macro(
                                     column_array = false;
                                 }
                             }
-                            _ => {
+                            token => {
                                 return Err(Error::unsupported_schema_format(
                                     "Invalid column definition token in diesel table macro",
-                                ))
+                                )
+                                .with_location(location(token.span(), &table_name_ident)))
                             }
                         }
                     }
@@ -360,31 +745,47 @@ fn handle_table_macro(
                         || column_unsigned
                     {
                         // looks like a column was in the middle of being parsed, let's panic!
+                        let mut column_location = location(
+                            rust_column_name
+                                .as_ref()
+                                .map_or(group.span_close(), |v| v.span()),
+                            &table_name_ident,
+                        );
+                        if let Some(name) =
+                            actual_column_name.or(rust_column_name.map(|v| v.to_string()))
+                        {
+                            column_location = column_location.column_name(name);
+                        }
+
                         return Err(Error::unsupported_schema_format(
                             "It seems a column was partially defined",
-                        ));
+                        )
+                        .with_location(column_location));
                     }
                 } else {
                     return Err(Error::unsupported_schema_format(
                         "Invalid delimiter in diesel table macro group",
-                    ));
+                    )
+                    .with_location(location(group.span(), &table_name_ident)));
                 }
             }
-            _ => {
+            token => {
                 return Err(Error::unsupported_schema_format(
                     "Invalid token tree item in diesel table macro",
-                ))
+                )
+                .with_location(location(token.span(), &table_name_ident)))
             }
         }
     }
 
     Ok(ParsedTableMacro {
-        name: table_name_ident
-            .clone()
-            .ok_or(Error::unsupported_schema_format(
-                "Could not extract table name from schema file",
-            ))?,
+        name: table_name_ident.clone().ok_or(
+            Error::unsupported_schema_format("Could not extract table name from schema file")
+                .with_location(macro_location),
+        )?,
         struct_name: table_name_ident.unwrap().to_string().to_pascal_case(),
+        doc_comments: table_doc_comments,
+        schema_module,
         columns: table_columns,
         primary_key_columns: table_primary_key_idents,
         foreign_keys: vec![],
@@ -428,6 +829,13 @@ fn parse_diesel_attr_group(group: &proc_macro2::Group) -> Option<(Ident, String)
         _ => return None,
     };
 
+    // parse string literals properly, to handle escapes (which are common in doc comments)
+    if let Ok(lit_str) =
+        syn::parse2::<syn::LitStr>(proc_macro2::TokenTree::Literal(value.clone()).into())
+    {
+        return Some((attr_name, lit_str.value()));
+    }
+
     let mut value = value.to_string();
 
     // remove the starting and ending quotes
@@ -438,6 +846,14 @@ fn parse_diesel_attr_group(group: &proc_macro2::Group) -> Option<(Ident, String)
     Some((attr_name, value))
 }
 
+/// Split the value of a `#[doc = "..."]` attribute into lines
+///
+/// A single attribute may contain multiple lines (for example from `/** */` comments)
+fn split_doc_comment(value: &str) -> impl Iterator<Item = String> + '_ {
+    // not using ".lines()", because empty lines (like a empty "///") need to be kept
+    value.split('\n').map(|line| line.trim_end().to_string())
+}
+
 // A function to translate diesel schema types into rust types
 //
 // reference: https://github.com/diesel-rs/diesel/blob/master/diesel/src/sql_types/mod.rs
@@ -445,12 +861,23 @@ fn parse_diesel_attr_group(group: &proc_macro2::Group) -> Option<(Ident, String)
 //
 // The docs page for sql_types is comprehensive but it hides some alias types like Int4, Float8, etc.:
 // https://docs.rs/diesel/latest/diesel/sql_types/index.html
-fn schema_type_to_rust_type(schema_type: String, config: &GenerationConfig) -> Result<String> {
-    Ok(match schema_type.to_lowercase().as_str() {
-        "unsigned" => return Err(Error::unsupported_type("Unsigned types are not yet supported, please open an issue if you need this feature!")), // TODO: deal with this later
-        "inet" => return Err(Error::unsupported_type("Unsigned types are not yet supported, please open an issue if you need this feature!")), // TODO: deal with this later
-        "cidr" => return Err(Error::unsupported_type("Unsigned types are not yet supported, please open an issue if you need this feature!")), // TODO: deal with this later
+fn schema_type_to_rust_type(
+    schema_type: String,
+    is_unsigned: bool,
+    schema_module: Option<&Ident>,
+    config: &GenerationConfig,
+    sql_types: &[ParsedSqlType],
+) -> Result<String> {
+    // checked before the type overrides, because those are for the signed types (like "Integer")
+    if is_unsigned {
+        return schema_type_to_unsigned_rust_type(&schema_type);
+    }
+
+    if let Some(ty) = config.get_type_override(&schema_type) {
+        return Ok(ty.to_string());
+    }
 
+    Ok(match schema_type.to_lowercase().as_str() {
         // boolean
         "bool" => "bool",
 
@@ -514,19 +941,57 @@ fn schema_type_to_rust_type(schema_type: String, config: &GenerationConfig) -> R
         "interval" => "PgInterval",
         "oid" => "u32",
         "money" => "PgMoney",
+
+        // network
         "macaddr" => "[u8; 6]",
-        // "inet" => "either ipnetwork::IpNetwork or ipnet::IpNet (TODO)",
-        // "cidr" => "either ipnetwork::IpNetwork or ipnet::IpNet (TODO)",
+        "macaddr8" => "[u8; 8]",
+        "inet" => config.get_network_address_type().as_str(),
+        "cidr" => config.get_network_address_type().as_str(),
 
         /*
             // panic if no type is found (this means generation is broken for this particular schema)
             _ => panic!("Unknown type found '{schema_type}', please report this!")
          */
         _ => {
-            let schema_path = config.get_schema_path();
+            // use the generated enum, if the type is a postgres enum with known variants
+            if let Some(sql_type) = sql_types
+                .iter()
+                .find(|v| v.name == schema_type && v.schema_module.as_ref() == schema_module)
+            {
+                if config.get_enum_variants(&sql_type.postgres_name).is_some() {
+                    return Ok(sql_type.enum_path(config));
+                }
+            }
+
+            let schema_path = match schema_module {
+                Some(module) => format!("{}{module}::", config.get_schema_path()),
+                None => config.get_schema_path().to_string(),
+            };
             // return the schema type if no type is found (this means generation is broken for this particular schema)
             let _type = format!("{schema_path}sql_types::{schema_type}");
             return Ok(_type);
         }
     }.to_string())
 }
+
+// A function to translate diesel schema types wrapped in `Unsigned<>` (mysql) into rust types
+//
+// diesel only implements `Unsigned<>` for the integer types:
+// https://docs.rs/diesel/latest/diesel/sql_types/struct.Unsigned.html
+fn schema_type_to_unsigned_rust_type(schema_type: &str) -> Result<String> {
+    Ok(match schema_type.to_lowercase().as_str() {
+        "tinyint" => "u8",
+        "smallint" => "u16",
+        "int2" => "u16",
+        "integer" => "u32",
+        "int4" => "u32",
+        "bigint" => "u64",
+        "int8" => "u64",
+        _ => {
+            return Err(Error::unsupported_type(format!(
+                "Unsigned<{schema_type}> is not supported, only integer types can be unsigned"
+            )))
+        }
+    }
+    .to_string())
+}
@@ -79,6 +79,27 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Result of a `.paginate_after` function
+#[derive(Debug, serde::Serialize)]
+pub struct CursorPaginationResult<T, C> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// Cursor to get the next page with, `None` if this is the last page
+    pub next_cursor: Option<C>,
+    /// Size of a page
+    pub page_size: i64,
+}
+
+/// Direction to order by, used by the `OrderBy` structs
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Ascending order (smallest first)
+    #[default]
+    Asc,
+    /// Descending order (largest first)
+    Desc,
+}
+
 impl Todos {
     /// Insert a new row into `todos` with a given [`CreateTodos`]
     pub fn create(db: &mut ConnectionType, item: &CreateTodos) -> diesel::QueryResult<Self> {
@@ -87,6 +108,13 @@ impl Todos {
         diesel::insert_into(todos).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `todos` with the given [`CreateTodos`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTodos]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::insert_into(todos).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `todos`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
         use crate::schema::todos::dsl::*;
@@ -96,10 +124,15 @@ impl Todos {
 
     /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut ConnectionType, page: i64, page_size: i64, filter: TodosFilter) -> diesel::QueryResult<PaginationResult<Self>> {
+        Self::paginate_ordered(db, page, page_size, filter, TodosOrderBy::default())
+    }
+
+    /// Same as [`Self::paginate`], but the pages are ordered by `order_by`
+    pub fn paginate_ordered(db: &mut ConnectionType, page: i64, page_size: i64, filter: TodosFilter, order_by: TodosOrderBy) -> diesel::QueryResult<PaginationResult<Self>> {
         let page = page.max(0);
         let page_size = page_size.max(1);
         let total_items = Self::filter(filter.clone()).count().get_result(db)?;
-        let items = Self::filter(filter).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = Self::filter_ordered(filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
@@ -111,6 +144,31 @@ impl Todos {
         })
     }
 
+    /// Paginates through the table ordered by `id`, starting after the given cursor (`None` for the first page)
+    ///
+    /// Unlike `paginate`, this does not count all rows and does not use a offset, which makes it suitable for large tables
+    pub fn paginate_after(db: &mut ConnectionType, cursor: Option<TodosCursor>, page_size: i64, filter: TodosFilter) -> diesel::QueryResult<CursorPaginationResult<Self, TodosCursor>> {
+        let page_size = page_size.max(1);
+        let mut query = Self::filter(filter);
+
+        if let Some(cursor) = &cursor {
+            query = query.filter(crate::schema::todos::id.gt(&cursor.id));
+        }
+
+        let items = query.order(crate::schema::todos::id.asc()).limit(page_size).load::<Self>(db)?;
+        let next_cursor = if items.len() as i64 == page_size {
+            items.last().map(|item| TodosCursor { id: item.id.clone() })
+        } else {
+            None
+        };
+
+        Ok(CursorPaginationResult {
+            items,
+            next_cursor,
+            page_size,
+        })
+    }
+
     /// A utility function to help build custom search queries
     /// 
     /// Example:
@@ -125,6 +183,8 @@ impl Todos {
     /// // delete completed todos
     /// diesel::delete(query).execute(db)?;
     /// ```
+    ///
+    /// The query is not ordered, use [`Self::filter_ordered`] or [`TodosOrderBy::apply`] to order it
     pub fn filter<'a>(
         filter: TodosFilter,
     ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
@@ -133,31 +193,132 @@ impl Todos {
         if let Some(filter_id) = filter.id {
             query = query.filter(crate::schema::todos::id.eq(filter_id));
         }
+        if let Some(filter_id_in) = filter.id_in {
+            query = query.filter(crate::schema::todos::id.eq_any(filter_id_in));
+        }
+        if let Some(filter_id_gt) = filter.id_gt {
+            query = query.filter(crate::schema::todos::id.gt(filter_id_gt));
+        }
+        if let Some(filter_id_gte) = filter.id_gte {
+            query = query.filter(crate::schema::todos::id.ge(filter_id_gte));
+        }
+        if let Some(filter_id_lt) = filter.id_lt {
+            query = query.filter(crate::schema::todos::id.lt(filter_id_lt));
+        }
+        if let Some(filter_id_lte) = filter.id_lte {
+            query = query.filter(crate::schema::todos::id.le(filter_id_lte));
+        }
         if let Some(filter_text) = filter.text {
             query = query.filter(crate::schema::todos::text.eq(filter_text));
         }
+        if let Some(filter_text_in) = filter.text_in {
+            query = query.filter(crate::schema::todos::text.eq_any(filter_text_in));
+        }
+        if let Some(filter_text_like) = filter.text_like {
+            query = query.filter(crate::schema::todos::text.like(filter_text_like));
+        }
+        if let Some(filter_text_ilike) = filter.text_ilike {
+            query = query.filter(crate::schema::todos::text.ilike(filter_text_ilike));
+        }
         if let Some(filter_completed) = filter.completed {
             query = query.filter(crate::schema::todos::completed.eq(filter_completed));
         }
         if let Some(filter_type_) = filter.type_ {
             query = query.filter(crate::schema::todos::type_.eq(filter_type_));
         }
+        if let Some(filter_type_in) = filter.type_in {
+            query = query.filter(crate::schema::todos::type_.eq_any(filter_type_in));
+        }
+        if let Some(filter_type_like) = filter.type_like {
+            query = query.filter(crate::schema::todos::type_.like(filter_type_like));
+        }
+        if let Some(filter_type_ilike) = filter.type_ilike {
+            query = query.filter(crate::schema::todos::type_.ilike(filter_type_ilike));
+        }
         if let Some(filter_smallint) = filter.smallint {
             query = query.filter(crate::schema::todos::smallint.eq(filter_smallint));
         }
+        if let Some(filter_smallint_in) = filter.smallint_in {
+            query = query.filter(crate::schema::todos::smallint.eq_any(filter_smallint_in));
+        }
+        if let Some(filter_smallint_gt) = filter.smallint_gt {
+            query = query.filter(crate::schema::todos::smallint.gt(filter_smallint_gt));
+        }
+        if let Some(filter_smallint_gte) = filter.smallint_gte {
+            query = query.filter(crate::schema::todos::smallint.ge(filter_smallint_gte));
+        }
+        if let Some(filter_smallint_lt) = filter.smallint_lt {
+            query = query.filter(crate::schema::todos::smallint.lt(filter_smallint_lt));
+        }
+        if let Some(filter_smallint_lte) = filter.smallint_lte {
+            query = query.filter(crate::schema::todos::smallint.le(filter_smallint_lte));
+        }
         if let Some(filter_bigint) = filter.bigint {
             query = query.filter(crate::schema::todos::bigint.eq(filter_bigint));
         }
+        if let Some(filter_bigint_in) = filter.bigint_in {
+            query = query.filter(crate::schema::todos::bigint.eq_any(filter_bigint_in));
+        }
+        if let Some(filter_bigint_gt) = filter.bigint_gt {
+            query = query.filter(crate::schema::todos::bigint.gt(filter_bigint_gt));
+        }
+        if let Some(filter_bigint_gte) = filter.bigint_gte {
+            query = query.filter(crate::schema::todos::bigint.ge(filter_bigint_gte));
+        }
+        if let Some(filter_bigint_lt) = filter.bigint_lt {
+            query = query.filter(crate::schema::todos::bigint.lt(filter_bigint_lt));
+        }
+        if let Some(filter_bigint_lte) = filter.bigint_lte {
+            query = query.filter(crate::schema::todos::bigint.le(filter_bigint_lte));
+        }
         if let Some(filter_created_at) = filter.created_at {
             query = query.filter(crate::schema::todos::created_at.eq(filter_created_at));
         }
+        if let Some(filter_created_at_in) = filter.created_at_in {
+            query = query.filter(crate::schema::todos::created_at.eq_any(filter_created_at_in));
+        }
+        if let Some(filter_created_at_gt) = filter.created_at_gt {
+            query = query.filter(crate::schema::todos::created_at.gt(filter_created_at_gt));
+        }
+        if let Some(filter_created_at_gte) = filter.created_at_gte {
+            query = query.filter(crate::schema::todos::created_at.ge(filter_created_at_gte));
+        }
+        if let Some(filter_created_at_lt) = filter.created_at_lt {
+            query = query.filter(crate::schema::todos::created_at.lt(filter_created_at_lt));
+        }
+        if let Some(filter_created_at_lte) = filter.created_at_lte {
+            query = query.filter(crate::schema::todos::created_at.le(filter_created_at_lte));
+        }
         if let Some(filter_updated_at) = filter.updated_at {
             query = query.filter(crate::schema::todos::updated_at.eq(filter_updated_at));
         }
+        if let Some(filter_updated_at_in) = filter.updated_at_in {
+            query = query.filter(crate::schema::todos::updated_at.eq_any(filter_updated_at_in));
+        }
+        if let Some(filter_updated_at_gt) = filter.updated_at_gt {
+            query = query.filter(crate::schema::todos::updated_at.gt(filter_updated_at_gt));
+        }
+        if let Some(filter_updated_at_gte) = filter.updated_at_gte {
+            query = query.filter(crate::schema::todos::updated_at.ge(filter_updated_at_gte));
+        }
+        if let Some(filter_updated_at_lt) = filter.updated_at_lt {
+            query = query.filter(crate::schema::todos::updated_at.lt(filter_updated_at_lt));
+        }
+        if let Some(filter_updated_at_lte) = filter.updated_at_lte {
+            query = query.filter(crate::schema::todos::updated_at.le(filter_updated_at_lte));
+        }
         
         query
     }
 
+    /// Same as [`Self::filter`], but ordered by `order_by`
+    pub fn filter_ordered<'a>(
+        filter: TodosFilter,
+        order_by: TodosOrderBy,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        order_by.apply(Self::filter(filter))
+    }
+
     /// Update a row in `todos`, identified by the primary key with [`UpdateTodos`]
     pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateTodos) -> diesel::QueryResult<Self> {
         use crate::schema::todos::dsl::*;
@@ -175,11 +336,140 @@ impl Todos {
 #[derive(Debug, Default, Clone)]
 pub struct TodosFilter {
     pub id: Option<i32>,
+    pub id_in: Option<Vec<i32>>,
+    pub id_gt: Option<i32>,
+    pub id_gte: Option<i32>,
+    pub id_lt: Option<i32>,
+    pub id_lte: Option<i32>,
     pub text: Option<String>,
+    pub text_in: Option<Vec<String>>,
+    pub text_like: Option<String>,
+    pub text_ilike: Option<String>,
     pub completed: Option<bool>,
     pub type_: Option<String>,
+    pub type_in: Option<Vec<String>>,
+    pub type_like: Option<String>,
+    pub type_ilike: Option<String>,
     pub smallint: Option<i16>,
+    pub smallint_in: Option<Vec<i16>>,
+    pub smallint_gt: Option<i16>,
+    pub smallint_gte: Option<i16>,
+    pub smallint_lt: Option<i16>,
+    pub smallint_lte: Option<i16>,
     pub bigint: Option<i64>,
+    pub bigint_in: Option<Vec<i64>>,
+    pub bigint_gt: Option<i64>,
+    pub bigint_gte: Option<i64>,
+    pub bigint_lt: Option<i64>,
+    pub bigint_lte: Option<i64>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at_in: Option<Vec<chrono::DateTime<chrono::Utc>>>,
+    pub created_at_gt: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at_gte: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at_lt: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at_lte: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    pub updated_at_in: Option<Vec<chrono::NaiveDateTime>>,
+    pub updated_at_gt: Option<chrono::NaiveDateTime>,
+    pub updated_at_gte: Option<chrono::NaiveDateTime>,
+    pub updated_at_lt: Option<chrono::NaiveDateTime>,
+    pub updated_at_lte: Option<chrono::NaiveDateTime>,
+}
+
+/// Position after the last row of a page of [`Todos::paginate_after`], to get the next page with
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TodosCursor {
+    pub id: i32,
+}
+
+/// All columns of `todos`, for example to order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodosColumn {
+    /// Column `id`
+    Id,
+    /// Column `text`
+    Text,
+    /// Column `completed`
+    Completed,
+    /// Column `type_`
+    Type,
+    /// Column `smallint`
+    Smallint,
+    /// Column `bigint`
+    Bigint,
+    /// Column `created_at`
+    CreatedAt,
+    /// Column `updated_at`
+    UpdatedAt,
+}
+
+impl std::str::FromStr for TodosColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "id" => Ok(Self::Id),
+            "text" => Ok(Self::Text),
+            "completed" => Ok(Self::Completed),
+            "type_" => Ok(Self::Type),
+            "smallint" => Ok(Self::Smallint),
+            "bigint" => Ok(Self::Bigint),
+            "created_at" => Ok(Self::CreatedAt),
+            "updated_at" => Ok(Self::UpdatedAt),
+            _ => Err(format!("unknown column \"{s}\" in table \"todos\"")),
+        }
+    }
+}
+
+/// Ordering for queries of `todos`, by one or multiple columns (the first having the highest priority)
+///
+/// Can be parsed from a comma-separated list of columns, where a `-` prefix means descending order (like `-created_at,title`)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TodosOrderBy(pub Vec<(TodosColumn, SortDirection)>);
+
+impl TodosOrderBy {
+    /// Apply the ordering to a query of `todos`, like the one from [`Todos::filter`]
+    pub fn apply<'a>(
+        &self,
+        mut query: crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg>,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        for (column, direction) in self.0.iter() {
+            query = match (column, direction) {
+                (TodosColumn::Id, SortDirection::Asc) => query.then_order_by(crate::schema::todos::id.asc()),
+                (TodosColumn::Id, SortDirection::Desc) => query.then_order_by(crate::schema::todos::id.desc()),
+                (TodosColumn::Text, SortDirection::Asc) => query.then_order_by(crate::schema::todos::text.asc()),
+                (TodosColumn::Text, SortDirection::Desc) => query.then_order_by(crate::schema::todos::text.desc()),
+                (TodosColumn::Completed, SortDirection::Asc) => query.then_order_by(crate::schema::todos::completed.asc()),
+                (TodosColumn::Completed, SortDirection::Desc) => query.then_order_by(crate::schema::todos::completed.desc()),
+                (TodosColumn::Type, SortDirection::Asc) => query.then_order_by(crate::schema::todos::type_.asc()),
+                (TodosColumn::Type, SortDirection::Desc) => query.then_order_by(crate::schema::todos::type_.desc()),
+                (TodosColumn::Smallint, SortDirection::Asc) => query.then_order_by(crate::schema::todos::smallint.asc()),
+                (TodosColumn::Smallint, SortDirection::Desc) => query.then_order_by(crate::schema::todos::smallint.desc()),
+                (TodosColumn::Bigint, SortDirection::Asc) => query.then_order_by(crate::schema::todos::bigint.asc()),
+                (TodosColumn::Bigint, SortDirection::Desc) => query.then_order_by(crate::schema::todos::bigint.desc()),
+                (TodosColumn::CreatedAt, SortDirection::Asc) => query.then_order_by(crate::schema::todos::created_at.asc()),
+                (TodosColumn::CreatedAt, SortDirection::Desc) => query.then_order_by(crate::schema::todos::created_at.desc()),
+                (TodosColumn::UpdatedAt, SortDirection::Asc) => query.then_order_by(crate::schema::todos::updated_at.asc()),
+                (TodosColumn::UpdatedAt, SortDirection::Desc) => query.then_order_by(crate::schema::todos::updated_at.desc()),
+            };
+        }
+
+        query
+    }
+}
+
+impl std::str::FromStr for TodosOrderBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| match v.strip_prefix('-') {
+                Some(column) => Ok((column.parse()?, SortDirection::Desc)),
+                None => Ok((v.strip_prefix('+').unwrap_or(v).parse()?, SortDirection::Asc)),
+            })
+            .collect::<std::result::Result<Vec<_>, Self::Err>>()
+            .map(Self)
+    }
 }
@@ -55,6 +55,13 @@ impl Todos {
         diesel::insert_into(todos).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `todos` with the given [`CreateTodos`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTodos]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::insert_into(todos).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `todos`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
         use crate::schema::todos::dsl::*;
@@ -0,0 +1,19 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+# fail on non-0 exit codes, so that a failed check fails the test
+set -e
+
+cargo run --manifest-path ../../Cargo.toml -- \
+-i schema.rs -o models -g id -g created_at -g updated_at -c "diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>"
+
+# the models have just been generated, so the check should not find any changes
+cargo run --manifest-path ../../Cargo.toml -- \
+-i schema.rs -o models -g id -g created_at -g updated_at -c "diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>" --check
+
+# the same goes for the diff, which should be empty
+cargo run --manifest-path ../../Cargo.toml -- \
+-i schema.rs -o models -g id -g created_at -g updated_at -c "diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>" --diff --check
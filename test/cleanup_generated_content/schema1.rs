@@ -14,3 +14,14 @@ diesel::table! {
         read -> Bool,
     }
 }
+
+pub mod archive {
+    diesel::table! {
+        archive.todos (id) {
+            id -> Int4,
+            text -> Text,
+            created_at -> Timestamptz,
+            updated_at -> Timestamptz,
+        }
+    }
+}
@@ -0,0 +1,353 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `orders`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=orders, primary_key(code))]
+pub struct Orders {
+    /// Field representing column `code`
+    pub code: crate::types::OrderCode,
+    /// Field representing column `email`
+    pub email: crate::types::Email,
+    /// Field representing column `status`
+    pub status: crate::OrderStatus,
+    /// Field representing column `note`
+    pub note: Option<String>,
+}
+
+/// Create Struct for a row in table `orders` for [`Orders`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=orders)]
+pub struct CreateOrders {
+    /// Field representing column `code`
+    pub code: crate::types::OrderCode,
+    /// Field representing column `email`
+    pub email: crate::types::Email,
+    /// Field representing column `status`
+    pub status: crate::OrderStatus,
+    /// Field representing column `note`
+    pub note: Option<String>,
+}
+
+/// Update Struct for a row in table `orders` for [`Orders`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=orders)]
+pub struct UpdateOrders {
+    /// Field representing column `email`
+    pub email: Option<crate::types::Email>,
+    /// Field representing column `status`
+    pub status: Option<crate::OrderStatus>,
+    /// Field representing column `note`
+    pub note: Option<Option<String>>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+/// Result of a `.paginate_after` function
+#[derive(Debug, serde::Serialize)]
+pub struct CursorPaginationResult<T, C> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// Cursor to get the next page with, `None` if this is the last page
+    pub next_cursor: Option<C>,
+    /// Size of a page
+    pub page_size: i64,
+}
+
+/// Direction to order by, used by the `OrderBy` structs
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Ascending order (smallest first)
+    #[default]
+    Asc,
+    /// Descending order (largest first)
+    Desc,
+}
+
+impl Orders {
+    /// Insert a new row into `orders` with a given [`CreateOrders`]
+    pub fn create(db: &mut ConnectionType, item: &CreateOrders) -> diesel::QueryResult<Self> {
+        use crate::schema::orders::dsl::*;
+
+        diesel::insert_into(orders).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `orders` with the given [`CreateOrders`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateOrders]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::orders::dsl::*;
+
+        diesel::insert_into(orders).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `orders`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_code: crate::types::OrderCode) -> diesel::QueryResult<Self> {
+        use crate::schema::orders::dsl::*;
+
+        orders.filter(code.eq(param_code)).first::<Self>(db)
+    }
+
+    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut ConnectionType, page: i64, page_size: i64, filter: OrdersFilter) -> diesel::QueryResult<PaginationResult<Self>> {
+        Self::paginate_ordered(db, page, page_size, filter, OrdersOrderBy::default())
+    }
+
+    /// Same as [`Self::paginate`], but the pages are ordered by `order_by`
+    pub fn paginate_ordered(db: &mut ConnectionType, page: i64, page_size: i64, filter: OrdersFilter, order_by: OrdersOrderBy) -> diesel::QueryResult<PaginationResult<Self>> {
+        let page = page.max(0);
+        let page_size = page_size.max(1);
+        let total_items = Self::filter(filter.clone()).count().get_result(db)?;
+        let items = Self::filter_ordered(filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by `code`, starting after the given cursor (`None` for the first page)
+    ///
+    /// Unlike `paginate`, this does not count all rows and does not use a offset, which makes it suitable for large tables
+    pub fn paginate_after(db: &mut ConnectionType, cursor: Option<OrdersCursor>, page_size: i64, filter: OrdersFilter) -> diesel::QueryResult<CursorPaginationResult<Self, OrdersCursor>> {
+        let page_size = page_size.max(1);
+        let mut query = Self::filter(filter);
+
+        if let Some(cursor) = &cursor {
+            query = query.filter(crate::schema::orders::code.gt(&cursor.code));
+        }
+
+        let items = query.order(crate::schema::orders::code.asc()).limit(page_size).load::<Self>(db)?;
+        let next_cursor = if items.len() as i64 == page_size {
+            items.last().map(|item| OrdersCursor { code: item.code.clone() })
+        } else {
+            None
+        };
+
+        Ok(CursorPaginationResult {
+            items,
+            next_cursor,
+            page_size,
+        })
+    }
+
+    /// A utility function to help build custom search queries
+    /// 
+    /// Example:
+    /// 
+    /// ```
+    /// // create a filter for completed todos
+    /// let query = Todo::filter(TodoFilter {
+    ///     completed: Some(true),
+    ///     ..Default::default()
+    /// });
+    /// 
+    /// // delete completed todos
+    /// diesel::delete(query).execute(db)?;
+    /// ```
+    ///
+    /// The query is not ordered, use [`Self::filter_ordered`] or [`OrdersOrderBy::apply`] to order it
+    pub fn filter<'a>(
+        filter: OrdersFilter,
+    ) -> crate::schema::orders::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = crate::schema::orders::table.into_boxed();
+        
+        if let Some(filter_code) = filter.code {
+            query = query.filter(crate::schema::orders::code.eq(filter_code));
+        }
+        if let Some(filter_code_in) = filter.code_in {
+            query = query.filter(crate::schema::orders::code.eq_any(filter_code_in));
+        }
+        if let Some(filter_code_like) = filter.code_like {
+            query = query.filter(crate::schema::orders::code.like(filter_code_like));
+        }
+        if let Some(filter_code_ilike) = filter.code_ilike {
+            query = query.filter(crate::schema::orders::code.ilike(filter_code_ilike));
+        }
+        if let Some(filter_email) = filter.email {
+            query = query.filter(crate::schema::orders::email.eq(filter_email));
+        }
+        if let Some(filter_email_in) = filter.email_in {
+            query = query.filter(crate::schema::orders::email.eq_any(filter_email_in));
+        }
+        if let Some(filter_email_like) = filter.email_like {
+            query = query.filter(crate::schema::orders::email.like(filter_email_like));
+        }
+        if let Some(filter_email_ilike) = filter.email_ilike {
+            query = query.filter(crate::schema::orders::email.ilike(filter_email_ilike));
+        }
+        if let Some(filter_status) = filter.status {
+            query = query.filter(crate::schema::orders::status.eq(filter_status));
+        }
+        if let Some(filter_status_in) = filter.status_in {
+            query = query.filter(crate::schema::orders::status.eq_any(filter_status_in));
+        }
+        if let Some(filter_status_like) = filter.status_like {
+            query = query.filter(crate::schema::orders::status.like(filter_status_like));
+        }
+        if let Some(filter_status_ilike) = filter.status_ilike {
+            query = query.filter(crate::schema::orders::status.ilike(filter_status_ilike));
+        }
+        if let Some(filter_note) = filter.note {
+            query = if filter_note.is_some() { 
+                query.filter(crate::schema::orders::note.eq(filter_note))
+            } else {
+                query.filter(crate::schema::orders::note.is_null())
+            };
+        }
+        if let Some(filter_note_in) = filter.note_in {
+            query = query.filter(crate::schema::orders::note.eq_any(filter_note_in));
+        }
+        if let Some(filter_note_like) = filter.note_like {
+            query = query.filter(crate::schema::orders::note.like(filter_note_like));
+        }
+        if let Some(filter_note_ilike) = filter.note_ilike {
+            query = query.filter(crate::schema::orders::note.ilike(filter_note_ilike));
+        }
+        if filter.note_is_not_null {
+            query = query.filter(crate::schema::orders::note.is_not_null());
+        }
+        
+        query
+    }
+
+    /// Same as [`Self::filter`], but ordered by `order_by`
+    pub fn filter_ordered<'a>(
+        filter: OrdersFilter,
+        order_by: OrdersOrderBy,
+    ) -> crate::schema::orders::BoxedQuery<'a, diesel::pg::Pg> {
+        order_by.apply(Self::filter(filter))
+    }
+
+    /// Update a row in `orders`, identified by the primary key with [`UpdateOrders`]
+    pub fn update(db: &mut ConnectionType, param_code: crate::types::OrderCode, item: &UpdateOrders) -> diesel::QueryResult<Self> {
+        use crate::schema::orders::dsl::*;
+
+        diesel::update(orders.filter(code.eq(param_code))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `orders`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_code: crate::types::OrderCode) -> diesel::QueryResult<usize> {
+        use crate::schema::orders::dsl::*;
+
+        diesel::delete(orders.filter(code.eq(param_code))).execute(db)
+    }
+}
+#[derive(Debug, Default, Clone)]
+pub struct OrdersFilter {
+    pub code: Option<crate::types::OrderCode>,
+    pub code_in: Option<Vec<crate::types::OrderCode>>,
+    pub code_like: Option<String>,
+    pub code_ilike: Option<String>,
+    pub email: Option<crate::types::Email>,
+    pub email_in: Option<Vec<crate::types::Email>>,
+    pub email_like: Option<String>,
+    pub email_ilike: Option<String>,
+    pub status: Option<crate::OrderStatus>,
+    pub status_in: Option<Vec<crate::OrderStatus>>,
+    pub status_like: Option<String>,
+    pub status_ilike: Option<String>,
+    pub note: Option<Option<String>>,
+    pub note_in: Option<Vec<String>>,
+    pub note_like: Option<String>,
+    pub note_ilike: Option<String>,
+    pub note_is_not_null: bool,
+}
+
+/// Position after the last row of a page of [`Orders::paginate_after`], to get the next page with
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OrdersCursor {
+    pub code: crate::types::OrderCode,
+}
+
+/// All columns of `orders`, for example to order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrdersColumn {
+    /// Column `code`
+    Code,
+    /// Column `email`
+    Email,
+    /// Column `status`
+    Status,
+    /// Column `note`
+    Note,
+}
+
+impl std::str::FromStr for OrdersColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "code" => Ok(Self::Code),
+            "email" => Ok(Self::Email),
+            "status" => Ok(Self::Status),
+            "note" => Ok(Self::Note),
+            _ => Err(format!("unknown column \"{s}\" in table \"orders\"")),
+        }
+    }
+}
+
+/// Ordering for queries of `orders`, by one or multiple columns (the first having the highest priority)
+///
+/// Can be parsed from a comma-separated list of columns, where a `-` prefix means descending order (like `-created_at,title`)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OrdersOrderBy(pub Vec<(OrdersColumn, SortDirection)>);
+
+impl OrdersOrderBy {
+    /// Apply the ordering to a query of `orders`, like the one from [`Orders::filter`]
+    pub fn apply<'a>(
+        &self,
+        mut query: crate::schema::orders::BoxedQuery<'a, diesel::pg::Pg>,
+    ) -> crate::schema::orders::BoxedQuery<'a, diesel::pg::Pg> {
+        for (column, direction) in self.0.iter() {
+            query = match (column, direction) {
+                (OrdersColumn::Code, SortDirection::Asc) => query.then_order_by(crate::schema::orders::code.asc()),
+                (OrdersColumn::Code, SortDirection::Desc) => query.then_order_by(crate::schema::orders::code.desc()),
+                (OrdersColumn::Email, SortDirection::Asc) => query.then_order_by(crate::schema::orders::email.asc()),
+                (OrdersColumn::Email, SortDirection::Desc) => query.then_order_by(crate::schema::orders::email.desc()),
+                (OrdersColumn::Status, SortDirection::Asc) => query.then_order_by(crate::schema::orders::status.asc()),
+                (OrdersColumn::Status, SortDirection::Desc) => query.then_order_by(crate::schema::orders::status.desc()),
+                (OrdersColumn::Note, SortDirection::Asc) => query.then_order_by(crate::schema::orders::note.asc()),
+                (OrdersColumn::Note, SortDirection::Desc) => query.then_order_by(crate::schema::orders::note.desc()),
+            };
+        }
+
+        query
+    }
+}
+
+impl std::str::FromStr for OrdersOrderBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| match v.strip_prefix('-') {
+                Some(column) => Ok((column.parse()?, SortDirection::Desc)),
+                None => Ok((v.strip_prefix('+').unwrap_or(v).parse()?, SortDirection::Asc)),
+            })
+            .collect::<std::result::Result<Vec<_>, Self::Err>>()
+            .map(Self)
+    }
+}
@@ -0,0 +1,8 @@
+diesel::table! {
+    orders (code) {
+        code -> Text,
+        email -> Text,
+        status -> Text,
+        note -> Nullable<Text>,
+    }
+}
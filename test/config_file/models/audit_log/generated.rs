@@ -0,0 +1,29 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+use crate::models::common::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `audit_log`
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=audit_log, primary_key(id))]
+pub struct AuditLog {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `message`
+    pub message: String,
+    /// Field representing column `created_at`
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl AuditLog {
+    /// Get a row from `audit_log`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::audit_log::dsl::*;
+
+        audit_log.filter(id.eq(param_id)).first::<Self>(db)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod common;
+pub mod todos;
+pub mod audit_log;
@@ -0,0 +1,13 @@
+diesel::table! {
+    todos (id) {
+        id -> Int4,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
@@ -0,0 +1,293 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `user_roles`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=user_roles, primary_key(user_id,role_id))]
+pub struct UserRoles {
+    /// Field representing column `user_id`
+    pub user_id: i32,
+    /// Field representing column `role_id`
+    pub role_id: i32,
+}
+
+/// Create Struct for a row in table `user_roles` for [`UserRoles`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=user_roles)]
+pub struct CreateUserRoles {
+    /// Field representing column `user_id`
+    pub user_id: i32,
+    /// Field representing column `role_id`
+    pub role_id: i32,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+/// Result of a `.paginate_after` function
+#[derive(Debug, serde::Serialize)]
+pub struct CursorPaginationResult<T, C> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// Cursor to get the next page with, `None` if this is the last page
+    pub next_cursor: Option<C>,
+    /// Size of a page
+    pub page_size: i64,
+}
+
+/// Direction to order by, used by the `OrderBy` structs
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Ascending order (smallest first)
+    #[default]
+    Asc,
+    /// Descending order (largest first)
+    Desc,
+}
+
+impl UserRoles {
+    /// Insert a new row into `user_roles` with a given [`CreateUserRoles`]
+    pub fn create(db: &mut ConnectionType, item: &CreateUserRoles) -> diesel::QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::insert_into(user_roles).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `user_roles` with the given [`CreateUserRoles`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateUserRoles]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::insert_into(user_roles).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `user_roles`, identified by the primary keys
+    pub fn read(db: &mut ConnectionType, param_user_id: i32, param_role_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut ConnectionType, page: i64, page_size: i64, filter: UserRolesFilter) -> diesel::QueryResult<PaginationResult<Self>> {
+        Self::paginate_ordered(db, page, page_size, filter, UserRolesOrderBy::default())
+    }
+
+    /// Same as [`Self::paginate`], but the pages are ordered by `order_by`
+    pub fn paginate_ordered(db: &mut ConnectionType, page: i64, page_size: i64, filter: UserRolesFilter, order_by: UserRolesOrderBy) -> diesel::QueryResult<PaginationResult<Self>> {
+        let page = page.max(0);
+        let page_size = page_size.max(1);
+        let total_items = Self::filter(filter.clone()).count().get_result(db)?;
+        let items = Self::filter_ordered(filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by `user_id`, `role_id`, starting after the given cursor (`None` for the first page)
+    ///
+    /// Unlike `paginate`, this does not count all rows and does not use a offset, which makes it suitable for large tables
+    pub fn paginate_after(db: &mut ConnectionType, cursor: Option<UserRolesCursor>, page_size: i64, filter: UserRolesFilter) -> diesel::QueryResult<CursorPaginationResult<Self, UserRolesCursor>> {
+        let page_size = page_size.max(1);
+        let mut query = Self::filter(filter);
+
+        if let Some(cursor) = &cursor {
+            query = query.filter(crate::schema::user_roles::user_id.gt(&cursor.user_id).or(crate::schema::user_roles::user_id.eq(&cursor.user_id).and(crate::schema::user_roles::role_id.gt(&cursor.role_id))));
+        }
+
+        let items = query.order((crate::schema::user_roles::user_id.asc(), crate::schema::user_roles::role_id.asc())).limit(page_size).load::<Self>(db)?;
+        let next_cursor = if items.len() as i64 == page_size {
+            items.last().map(|item| UserRolesCursor { user_id: item.user_id.clone(), role_id: item.role_id.clone() })
+        } else {
+            None
+        };
+
+        Ok(CursorPaginationResult {
+            items,
+            next_cursor,
+            page_size,
+        })
+    }
+
+    /// A utility function to help build custom search queries
+    /// 
+    /// Example:
+    /// 
+    /// ```
+    /// // create a filter for completed todos
+    /// let query = Todo::filter(TodoFilter {
+    ///     completed: Some(true),
+    ///     ..Default::default()
+    /// });
+    /// 
+    /// // delete completed todos
+    /// diesel::delete(query).execute(db)?;
+    /// ```
+    ///
+    /// The query is not ordered, use [`Self::filter_ordered`] or [`UserRolesOrderBy::apply`] to order it
+    pub fn filter<'a>(
+        filter: UserRolesFilter,
+    ) -> crate::schema::user_roles::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = crate::schema::user_roles::table.into_boxed();
+        
+        if let Some(filter_user_id) = filter.user_id {
+            query = query.filter(crate::schema::user_roles::user_id.eq(filter_user_id));
+        }
+        if let Some(filter_user_id_in) = filter.user_id_in {
+            query = query.filter(crate::schema::user_roles::user_id.eq_any(filter_user_id_in));
+        }
+        if let Some(filter_user_id_gt) = filter.user_id_gt {
+            query = query.filter(crate::schema::user_roles::user_id.gt(filter_user_id_gt));
+        }
+        if let Some(filter_user_id_gte) = filter.user_id_gte {
+            query = query.filter(crate::schema::user_roles::user_id.ge(filter_user_id_gte));
+        }
+        if let Some(filter_user_id_lt) = filter.user_id_lt {
+            query = query.filter(crate::schema::user_roles::user_id.lt(filter_user_id_lt));
+        }
+        if let Some(filter_user_id_lte) = filter.user_id_lte {
+            query = query.filter(crate::schema::user_roles::user_id.le(filter_user_id_lte));
+        }
+        if let Some(filter_role_id) = filter.role_id {
+            query = query.filter(crate::schema::user_roles::role_id.eq(filter_role_id));
+        }
+        if let Some(filter_role_id_in) = filter.role_id_in {
+            query = query.filter(crate::schema::user_roles::role_id.eq_any(filter_role_id_in));
+        }
+        if let Some(filter_role_id_gt) = filter.role_id_gt {
+            query = query.filter(crate::schema::user_roles::role_id.gt(filter_role_id_gt));
+        }
+        if let Some(filter_role_id_gte) = filter.role_id_gte {
+            query = query.filter(crate::schema::user_roles::role_id.ge(filter_role_id_gte));
+        }
+        if let Some(filter_role_id_lt) = filter.role_id_lt {
+            query = query.filter(crate::schema::user_roles::role_id.lt(filter_role_id_lt));
+        }
+        if let Some(filter_role_id_lte) = filter.role_id_lte {
+            query = query.filter(crate::schema::user_roles::role_id.le(filter_role_id_lte));
+        }
+        
+        query
+    }
+
+    /// Same as [`Self::filter`], but ordered by `order_by`
+    pub fn filter_ordered<'a>(
+        filter: UserRolesFilter,
+        order_by: UserRolesOrderBy,
+    ) -> crate::schema::user_roles::BoxedQuery<'a, diesel::pg::Pg> {
+        order_by.apply(Self::filter(filter))
+    }
+
+    /// Delete a row in `user_roles`, identified by the primary keys
+    pub fn delete(db: &mut ConnectionType, param_user_id: i32, param_role_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::delete(user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id))).execute(db)
+    }
+}
+#[derive(Debug, Default, Clone)]
+pub struct UserRolesFilter {
+    pub user_id: Option<i32>,
+    pub user_id_in: Option<Vec<i32>>,
+    pub user_id_gt: Option<i32>,
+    pub user_id_gte: Option<i32>,
+    pub user_id_lt: Option<i32>,
+    pub user_id_lte: Option<i32>,
+    pub role_id: Option<i32>,
+    pub role_id_in: Option<Vec<i32>>,
+    pub role_id_gt: Option<i32>,
+    pub role_id_gte: Option<i32>,
+    pub role_id_lt: Option<i32>,
+    pub role_id_lte: Option<i32>,
+}
+
+/// Position after the last row of a page of [`UserRoles::paginate_after`], to get the next page with
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UserRolesCursor {
+    pub user_id: i32,
+    pub role_id: i32,
+}
+
+/// All columns of `user_roles`, for example to order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserRolesColumn {
+    /// Column `user_id`
+    UserId,
+    /// Column `role_id`
+    RoleId,
+}
+
+impl std::str::FromStr for UserRolesColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "user_id" => Ok(Self::UserId),
+            "role_id" => Ok(Self::RoleId),
+            _ => Err(format!("unknown column \"{s}\" in table \"user_roles\"")),
+        }
+    }
+}
+
+/// Ordering for queries of `user_roles`, by one or multiple columns (the first having the highest priority)
+///
+/// Can be parsed from a comma-separated list of columns, where a `-` prefix means descending order (like `-created_at,title`)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UserRolesOrderBy(pub Vec<(UserRolesColumn, SortDirection)>);
+
+impl UserRolesOrderBy {
+    /// Apply the ordering to a query of `user_roles`, like the one from [`UserRoles::filter`]
+    pub fn apply<'a>(
+        &self,
+        mut query: crate::schema::user_roles::BoxedQuery<'a, diesel::pg::Pg>,
+    ) -> crate::schema::user_roles::BoxedQuery<'a, diesel::pg::Pg> {
+        for (column, direction) in self.0.iter() {
+            query = match (column, direction) {
+                (UserRolesColumn::UserId, SortDirection::Asc) => query.then_order_by(crate::schema::user_roles::user_id.asc()),
+                (UserRolesColumn::UserId, SortDirection::Desc) => query.then_order_by(crate::schema::user_roles::user_id.desc()),
+                (UserRolesColumn::RoleId, SortDirection::Asc) => query.then_order_by(crate::schema::user_roles::role_id.asc()),
+                (UserRolesColumn::RoleId, SortDirection::Desc) => query.then_order_by(crate::schema::user_roles::role_id.desc()),
+            };
+        }
+
+        query
+    }
+}
+
+impl std::str::FromStr for UserRolesOrderBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| match v.strip_prefix('-') {
+                Some(column) => Ok((column.parse()?, SortDirection::Desc)),
+                None => Ok((v.strip_prefix('+').unwrap_or(v).parse()?, SortDirection::Asc)),
+            })
+            .collect::<std::result::Result<Vec<_>, Self::Err>>()
+            .map(Self)
+    }
+}
@@ -45,6 +45,13 @@ impl TableA {
         diesel::insert_into(tableA).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `tableA` with the given [`CreateTableA`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTableA]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::data::schema::tableA::dsl::*;
+
+        diesel::insert_into(tableA).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `tableA`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param__id: i32) -> diesel::QueryResult<Self> {
         use crate::data::schema::tableA::dsl::*;
@@ -58,6 +58,13 @@ impl TableB {
         diesel::insert_into(tableB).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `tableB` with the given [`CreateTableB`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTableB]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::data::schema::tableB::dsl::*;
+
+        diesel::insert_into(tableB).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `tableB`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param__id: i32) -> diesel::QueryResult<Self> {
         use crate::data::schema::tableB::dsl::*;
@@ -65,6 +72,18 @@ impl TableB {
         tableB.filter(_id.eq(param__id)).first::<Self>(db)
     }
 
+    /// Get all rows of `tableB` which belong to the given [`TableA`] (by `link`)
+    pub fn for_table_a(db: &mut ConnectionType, parent: &TableA) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).load::<Self>(db)
+    }
+
+    /// Get all rows of `tableB` which belong to any of the given [`TableA`] (by `link`), grouped by their parent
+    pub fn for_table_a_grouped(db: &mut ConnectionType, parents: &[TableA]) -> diesel::QueryResult<Vec<(TableA, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
     /// Update a row in `tableB`, identified by the primary key with [`UpdateTableB`]
     pub fn update(db: &mut ConnectionType, param__id: i32, item: &UpdateTableB) -> diesel::QueryResult<Self> {
         use crate::data::schema::tableB::dsl::*;
@@ -0,0 +1,8 @@
+diesel::table! {
+    todos (id) {
+        id -> Int4,
+        owner_id -> Int4,
+        text -> Text,
+        created_at -> Timestamptz,
+    }
+}
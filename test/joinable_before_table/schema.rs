@@ -0,0 +1,19 @@
+// "joinable!" macros may appear before the tables they reference
+diesel::joinable!(todos -> users (user_id));
+
+diesel::table! {
+    todos (id) {
+        id -> Int4,
+        user_id -> Int4,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::allow_tables_to_appear_in_same_query!(todos, users,);
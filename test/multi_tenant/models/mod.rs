@@ -0,0 +1,3 @@
+pub mod projects;
+pub mod tenants;
+pub mod todos;
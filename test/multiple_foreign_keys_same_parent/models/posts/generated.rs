@@ -0,0 +1,141 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::models::users::Users;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `posts`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Associations, diesel::Identifiable)]
+#[diesel(table_name=posts, primary_key(id), belongs_to(Users, foreign_key=author_id))]
+pub struct Posts {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `author_id`
+    pub author_id: i32,
+    /// Field representing column `reviewer_id`
+    pub reviewer_id: Option<i32>,
+    /// Field representing column `title`
+    pub title: String,
+}
+
+/// Create Struct for a row in table `posts` for [`Posts`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=posts)]
+pub struct CreatePosts {
+    /// Field representing column `author_id`
+    pub author_id: i32,
+    /// Field representing column `reviewer_id`
+    pub reviewer_id: Option<i32>,
+    /// Field representing column `title`
+    pub title: String,
+}
+
+/// Update Struct for a row in table `posts` for [`Posts`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=posts)]
+pub struct UpdatePosts {
+    /// Field representing column `author_id`
+    pub author_id: Option<i32>,
+    /// Field representing column `reviewer_id`
+    pub reviewer_id: Option<Option<i32>>,
+    /// Field representing column `title`
+    pub title: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Posts {
+    /// Insert a new row into `posts` with a given [`CreatePosts`]
+    pub fn create(db: &mut ConnectionType, item: &CreatePosts) -> diesel::QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::insert_into(posts).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `posts` with the given [`CreatePosts`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreatePosts]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::insert_into(posts).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `posts`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        posts.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Get all rows of `posts` which belong to the given [`Users`] (by `author_id`)
+    pub fn for_user(db: &mut ConnectionType, parent: &Users) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).load::<Self>(db)
+    }
+
+    /// Get all rows of `posts` which belong to any of the given [`Users`] (by `author_id`), grouped by their parent
+    pub fn for_users(db: &mut ConnectionType, parents: &[Users]) -> diesel::QueryResult<Vec<(Users, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
+    /// Get all rows of `posts` which reference the given [`Users`] by `reviewer_id`
+    pub fn for_user_by_reviewer_id(db: &mut ConnectionType, parent: &Users) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        posts.filter(reviewer_id.eq(parent.id())).load::<Self>(db)
+    }
+
+    /// Get all rows of `posts` which reference any of the given [`Users`] by `reviewer_id`, grouped by their parent
+    pub fn for_users_by_reviewer_id(db: &mut ConnectionType, parents: &[Users]) -> diesel::QueryResult<Vec<(Users, Vec<Self>)>> {
+        use crate::schema::posts::dsl::*;
+
+        let children = posts.filter(reviewer_id.eq_any(parents.iter().map(|parent| parent.id()))).load::<Self>(db)?;
+
+        Ok(parents
+            .iter()
+            .map(|parent| {
+                let matching = children.iter().filter(|child| child.reviewer_id.as_ref() == Some(parent.id())).cloned().collect();
+                (parent.clone(), matching)
+            })
+            .collect())
+    }
+
+    /// Get all rows of `posts` together with the [`Users`] referenced by `reviewer_id`
+    pub fn with_reviewer(db: &mut ConnectionType) -> diesel::QueryResult<Vec<(Self, Option<Users>)>> {
+        use crate::schema::posts::dsl::*;
+
+        posts
+            .left_join(crate::schema::users::table.on(reviewer_id.eq(diesel::Table::primary_key(&crate::schema::users::table).nullable())))
+            .load::<(Self, Option<Users>)>(db)
+    }
+
+    /// Update a row in `posts`, identified by the primary key with [`UpdatePosts`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdatePosts) -> diesel::QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::update(posts.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `posts`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::delete(posts.filter(id.eq(param_id))).execute(db)
+    }
+}
@@ -0,0 +1,20 @@
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        author_id -> Int4,
+        reviewer_id -> Nullable<Int4>,
+        title -> Text,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+// diesel only allows one "joinable!" per pair of tables, "reviewer_id" is declared in dsync.toml
+diesel::joinable!(posts -> users (author_id));
+
+diesel::allow_tables_to_appear_in_same_query!(posts, users,);
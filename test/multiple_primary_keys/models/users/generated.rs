@@ -61,6 +61,13 @@ impl Users {
         diesel::insert_into(users).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `users` with the given [`CreateUsers`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateUsers]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::users::dsl::*;
+
+        diesel::insert_into(users).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `users`, identified by the primary keys
     pub fn read(db: &mut ConnectionType, param_name: String, param_address: String) -> diesel::QueryResult<Self> {
         use crate::schema::users::dsl::*;
@@ -0,0 +1,93 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::mysql::MysqlConnection>>;
+
+/// Struct representing a row in table `countries`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=countries, primary_key(code))]
+pub struct Countries {
+    /// Field representing column `code`
+    pub code: String,
+    /// Field representing column `name`
+    pub name: String,
+}
+
+/// Create Struct for a row in table `countries` for [`Countries`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=countries)]
+pub struct CreateCountries {
+    /// Field representing column `code`
+    pub code: String,
+    /// Field representing column `name`
+    pub name: String,
+}
+
+/// Update Struct for a row in table `countries` for [`Countries`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=countries)]
+pub struct UpdateCountries {
+    /// Field representing column `name`
+    pub name: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Countries {
+    /// Insert a new row into `countries` with a given [`CreateCountries`]
+    ///
+    /// The row is read again after inserting, because the backend does not support `RETURNING`
+    pub fn create(db: &mut ConnectionType, item: &CreateCountries) -> diesel::QueryResult<Self> {
+        use crate::schema::countries::dsl::*;
+
+        diesel::insert_into(countries).values(item).execute(db)?;
+        countries.filter(code.eq(&item.code)).first::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `countries` with the given [`CreateCountries`]s, returning the number of inserted rows
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateCountries]) -> diesel::QueryResult<usize> {
+        use crate::schema::countries::dsl::*;
+
+        diesel::insert_into(countries).values(items).execute(db)
+    }
+
+    /// Get a row from `countries`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_code: String) -> diesel::QueryResult<Self> {
+        use crate::schema::countries::dsl::*;
+
+        countries.filter(code.eq(param_code)).first::<Self>(db)
+    }
+
+    /// Update a row in `countries`, identified by the primary key with [`UpdateCountries`]
+    ///
+    /// The row is read again after updating, because the backend does not support `RETURNING`
+    pub fn update(db: &mut ConnectionType, param_code: String, item: &UpdateCountries) -> diesel::QueryResult<Self> {
+        use crate::schema::countries::dsl::*;
+
+        diesel::update(countries.filter(code.eq(&param_code))).set(item).execute(db)?;
+        countries.filter(code.eq(param_code)).first::<Self>(db)
+    }
+
+    /// Delete a row in `countries`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_code: String) -> diesel::QueryResult<usize> {
+        use crate::schema::countries::dsl::*;
+
+        diesel::delete(countries.filter(code.eq(param_code))).execute(db)
+    }
+}
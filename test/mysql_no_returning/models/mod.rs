@@ -0,0 +1,3 @@
+pub mod todos;
+pub mod countries;
+pub mod user_roles;
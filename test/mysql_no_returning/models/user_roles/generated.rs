@@ -0,0 +1,97 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::mysql::MysqlConnection>>;
+
+/// Struct representing a row in table `user_roles`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=user_roles, primary_key(user_id,role_id))]
+pub struct UserRoles {
+    /// Field representing column `user_id`
+    pub user_id: i32,
+    /// Field representing column `role_id`
+    pub role_id: i32,
+    /// Field representing column `granted_by`
+    pub granted_by: Option<i32>,
+}
+
+/// Create Struct for a row in table `user_roles` for [`UserRoles`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=user_roles)]
+pub struct CreateUserRoles {
+    /// Field representing column `user_id`
+    pub user_id: i32,
+    /// Field representing column `role_id`
+    pub role_id: i32,
+    /// Field representing column `granted_by`
+    pub granted_by: Option<i32>,
+}
+
+/// Update Struct for a row in table `user_roles` for [`UserRoles`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=user_roles)]
+pub struct UpdateUserRoles {
+    /// Field representing column `granted_by`
+    pub granted_by: Option<Option<i32>>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl UserRoles {
+    /// Insert a new row into `user_roles` with a given [`CreateUserRoles`]
+    ///
+    /// The row is read again after inserting, because the backend does not support `RETURNING`
+    pub fn create(db: &mut ConnectionType, item: &CreateUserRoles) -> diesel::QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::insert_into(user_roles).values(item).execute(db)?;
+        user_roles.filter(user_id.eq(&item.user_id)).filter(role_id.eq(&item.role_id)).first::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `user_roles` with the given [`CreateUserRoles`]s, returning the number of inserted rows
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateUserRoles]) -> diesel::QueryResult<usize> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::insert_into(user_roles).values(items).execute(db)
+    }
+
+    /// Get a row from `user_roles`, identified by the primary keys
+    pub fn read(db: &mut ConnectionType, param_user_id: i32, param_role_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `user_roles`, identified by the primary keys with [`UpdateUserRoles`]
+    ///
+    /// The row is read again after updating, because the backend does not support `RETURNING`
+    pub fn update(db: &mut ConnectionType, param_user_id: i32, param_role_id: i32, item: &UpdateUserRoles) -> diesel::QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::update(user_roles.filter(user_id.eq(&param_user_id)).filter(role_id.eq(&param_role_id))).set(item).execute(db)?;
+        user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id)).first::<Self>(db)
+    }
+
+    /// Delete a row in `user_roles`, identified by the primary keys
+    pub fn delete(db: &mut ConnectionType, param_user_id: i32, param_role_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::delete(user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id))).execute(db)
+    }
+}
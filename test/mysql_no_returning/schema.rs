@@ -0,0 +1,22 @@
+diesel::table! {
+    todos (id) {
+        id -> Integer,
+        text -> Text,
+        completed -> Bool,
+    }
+}
+
+diesel::table! {
+    countries (code) {
+        code -> Varchar,
+        name -> Varchar,
+    }
+}
+
+diesel::table! {
+    user_roles (user_id, role_id) {
+        user_id -> Integer,
+        role_id -> Integer,
+        granted_by -> Nullable<Integer>,
+    }
+}
@@ -0,0 +1,109 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::mysql::MysqlConnection>>;
+
+/// Struct representing a row in table `accounts`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=accounts, primary_key(id))]
+pub struct Accounts {
+    /// Field representing column `id`
+    pub id: u64,
+    /// Field representing column `age`
+    pub age: u8,
+    /// Field representing column `logins`
+    pub logins: u32,
+    /// Field representing column `rank`
+    pub rank: Option<u16>,
+    /// Field representing column `balance`
+    pub balance: i64,
+}
+
+/// Create Struct for a row in table `accounts` for [`Accounts`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=accounts)]
+pub struct CreateAccounts {
+    /// Field representing column `age`
+    pub age: u8,
+    /// Field representing column `logins`
+    pub logins: u32,
+    /// Field representing column `rank`
+    pub rank: Option<u16>,
+    /// Field representing column `balance`
+    pub balance: i64,
+}
+
+/// Update Struct for a row in table `accounts` for [`Accounts`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=accounts)]
+pub struct UpdateAccounts {
+    /// Field representing column `age`
+    pub age: Option<u8>,
+    /// Field representing column `logins`
+    pub logins: Option<u32>,
+    /// Field representing column `rank`
+    pub rank: Option<Option<u16>>,
+    /// Field representing column `balance`
+    pub balance: Option<i64>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Accounts {
+    /// Insert a new row into `accounts` with a given [`CreateAccounts`]
+    ///
+    /// The row is read again after inserting, because the backend does not support `RETURNING`
+    pub fn create(db: &mut ConnectionType, item: &CreateAccounts) -> diesel::QueryResult<Self> {
+        use crate::schema::accounts::dsl::*;
+
+        diesel::insert_into(accounts).values(item).execute(db)?;
+        accounts.filter(diesel::dsl::sql::<diesel::sql_types::Bool>("`id` = LAST_INSERT_ID()")).first::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `accounts` with the given [`CreateAccounts`]s, returning the number of inserted rows
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateAccounts]) -> diesel::QueryResult<usize> {
+        use crate::schema::accounts::dsl::*;
+
+        diesel::insert_into(accounts).values(items).execute(db)
+    }
+
+    /// Get a row from `accounts`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: u64) -> diesel::QueryResult<Self> {
+        use crate::schema::accounts::dsl::*;
+
+        accounts.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `accounts`, identified by the primary key with [`UpdateAccounts`]
+    ///
+    /// The row is read again after updating, because the backend does not support `RETURNING`
+    pub fn update(db: &mut ConnectionType, param_id: u64, item: &UpdateAccounts) -> diesel::QueryResult<Self> {
+        use crate::schema::accounts::dsl::*;
+
+        diesel::update(accounts.filter(id.eq(&param_id))).set(item).execute(db)?;
+        accounts.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Delete a row in `accounts`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: u64) -> diesel::QueryResult<usize> {
+        use crate::schema::accounts::dsl::*;
+
+        diesel::delete(accounts.filter(id.eq(param_id))).execute(db)
+    }
+}
@@ -0,0 +1,9 @@
+diesel::table! {
+    accounts (id) {
+        id -> Unsigned<Bigint>,
+        age -> Unsigned<Tinyint>,
+        logins -> Unsigned<Integer>,
+        rank -> Nullable<Unsigned<Smallint>>,
+        balance -> Bigint,
+    }
+}
@@ -0,0 +1,95 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `documents`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=documents, primary_key(id))]
+pub struct Documents {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `title`
+    pub title: String,
+    /// Field representing column `body`
+    pub body: String,
+    /// Field representing column `version`
+    pub version: i32,
+}
+
+/// Create Struct for a row in table `documents` for [`Documents`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=documents)]
+pub struct CreateDocuments {
+    /// Field representing column `title`
+    pub title: String,
+    /// Field representing column `body`
+    pub body: String,
+}
+
+/// Update Struct for a row in table `documents` for [`Documents`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=documents)]
+pub struct UpdateDocuments {
+    /// Field representing column `title`
+    pub title: Option<String>,
+    /// Field representing column `body`
+    pub body: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Documents {
+    /// Insert a new row into `documents` with a given [`CreateDocuments`]
+    pub fn create(db: &mut ConnectionType, item: &CreateDocuments) -> diesel::QueryResult<Self> {
+        use crate::schema::documents::dsl::*;
+
+        diesel::insert_into(documents).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `documents` with the given [`CreateDocuments`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateDocuments]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::documents::dsl::*;
+
+        diesel::insert_into(documents).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `documents`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::documents::dsl::*;
+
+        documents.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `documents`, identified by the primary key and the expected `version` with [`UpdateDocuments`], incrementing `version`
+    ///
+    /// Returns `None` if the row does not exist or was changed concurrently (stale version)
+    pub fn update(db: &mut ConnectionType, param_id: i32, expected_version: i32, item: &UpdateDocuments) -> diesel::QueryResult<Option<Self>> {
+        use crate::schema::documents::dsl::*;
+
+        diesel::update(documents.filter(id.eq(param_id)).filter(version.eq(expected_version))).set((item, version.eq(version + 1))).get_result(db).optional()
+    }
+
+    /// Delete a row in `documents`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::documents::dsl::*;
+
+        diesel::delete(documents.filter(id.eq(param_id))).execute(db)
+    }
+}
@@ -0,0 +1,8 @@
+diesel::table! {
+    documents (id) {
+        id -> Int4,
+        title -> Text,
+        body -> Text,
+        version -> Int4,
+    }
+}
@@ -61,6 +61,13 @@ impl User {
         diesel::insert_into(user).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `user` with the given [`CreateUser`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateUser]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::user::dsl::*;
+
+        diesel::insert_into(user).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `user`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
         use crate::schema::user::dsl::*;
@@ -0,0 +1,123 @@
+/* @generated and managed by dsync */
+
+/// Enum representing the postgres enum `task_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::TaskStatus)]
+pub enum TaskStatus {
+    /// Variant representing value `todo`
+    #[serde(rename = "todo")]
+    Todo,
+    /// Variant representing value `in_progress`
+    #[serde(rename = "in_progress")]
+    InProgress,
+    /// Variant representing value `done`
+    #[serde(rename = "done")]
+    Done,
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::TaskStatus, diesel::pg::Pg> for TaskStatus {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        match *self {
+            TaskStatus::Todo => out.write_all(b"todo")?,
+            TaskStatus::InProgress => out.write_all(b"in_progress")?,
+            TaskStatus::Done => out.write_all(b"done")?,
+        }
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::TaskStatus, diesel::pg::Pg> for TaskStatus {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"todo" => Ok(TaskStatus::Todo),
+            b"in_progress" => Ok(TaskStatus::InProgress),
+            b"done" => Ok(TaskStatus::Done),
+            _ => Err(format!("Unrecognized variant for enum `task_status`: {:?}", String::from_utf8_lossy(bytes.as_bytes())).into()),
+        }
+    }
+}
+
+/// Enum representing the postgres enum `priority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::Priority)]
+pub enum Priority {
+    /// Variant representing value `low`
+    #[serde(rename = "low")]
+    Low,
+    /// Variant representing value `medium`
+    #[serde(rename = "medium")]
+    Medium,
+    /// Variant representing value `high`
+    #[serde(rename = "high")]
+    High,
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::Priority, diesel::pg::Pg> for Priority {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        match *self {
+            Priority::Low => out.write_all(b"low")?,
+            Priority::Medium => out.write_all(b"medium")?,
+            Priority::High => out.write_all(b"high")?,
+        }
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::Priority, diesel::pg::Pg> for Priority {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"low" => Ok(Priority::Low),
+            b"medium" => Ok(Priority::Medium),
+            b"high" => Ok(Priority::High),
+            _ => Err(format!("Unrecognized variant for enum `priority`: {:?}", String::from_utf8_lossy(bytes.as_bytes())).into()),
+        }
+    }
+}
+
+pub mod tenant {
+    /// Enum representing the postgres enum `task_status`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)]
+    #[diesel(sql_type = crate::schema::tenant::sql_types::TaskStatus)]
+    pub enum TaskStatus {
+        /// Variant representing value `todo`
+        #[serde(rename = "todo")]
+        Todo,
+        /// Variant representing value `in_progress`
+        #[serde(rename = "in_progress")]
+        InProgress,
+        /// Variant representing value `done`
+        #[serde(rename = "done")]
+        Done,
+    }
+
+    impl diesel::serialize::ToSql<crate::schema::tenant::sql_types::TaskStatus, diesel::pg::Pg> for TaskStatus {
+        fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+            use std::io::Write;
+
+            match *self {
+                TaskStatus::Todo => out.write_all(b"todo")?,
+                TaskStatus::InProgress => out.write_all(b"in_progress")?,
+                TaskStatus::Done => out.write_all(b"done")?,
+            }
+
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl diesel::deserialize::FromSql<crate::schema::tenant::sql_types::TaskStatus, diesel::pg::Pg> for TaskStatus {
+        fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+            match bytes.as_bytes() {
+                b"todo" => Ok(TaskStatus::Todo),
+                b"in_progress" => Ok(TaskStatus::InProgress),
+                b"done" => Ok(TaskStatus::Done),
+                _ => Err(format!("Unrecognized variant for enum `task_status`: {:?}", String::from_utf8_lossy(bytes.as_bytes())).into()),
+            }
+        }
+    }
+}
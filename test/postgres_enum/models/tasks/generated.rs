@@ -0,0 +1,97 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `tasks`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=tasks, primary_key(id))]
+pub struct Tasks {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `title`
+    pub title: String,
+    /// Field representing column `status`
+    pub status: crate::models::enums::TaskStatus,
+    /// Field representing column `priority`
+    pub priority: Option<crate::models::enums::Priority>,
+}
+
+/// Create Struct for a row in table `tasks` for [`Tasks`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=tasks)]
+pub struct CreateTasks {
+    /// Field representing column `title`
+    pub title: String,
+    /// Field representing column `status`
+    pub status: crate::models::enums::TaskStatus,
+    /// Field representing column `priority`
+    pub priority: Option<crate::models::enums::Priority>,
+}
+
+/// Update Struct for a row in table `tasks` for [`Tasks`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=tasks)]
+pub struct UpdateTasks {
+    /// Field representing column `title`
+    pub title: Option<String>,
+    /// Field representing column `status`
+    pub status: Option<crate::models::enums::TaskStatus>,
+    /// Field representing column `priority`
+    pub priority: Option<Option<crate::models::enums::Priority>>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Tasks {
+    /// Insert a new row into `tasks` with a given [`CreateTasks`]
+    pub fn create(db: &mut ConnectionType, item: &CreateTasks) -> diesel::QueryResult<Self> {
+        use crate::schema::tasks::dsl::*;
+
+        diesel::insert_into(tasks).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `tasks` with the given [`CreateTasks`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTasks]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::tasks::dsl::*;
+
+        diesel::insert_into(tasks).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `tasks`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::tasks::dsl::*;
+
+        tasks.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `tasks`, identified by the primary key with [`UpdateTasks`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateTasks) -> diesel::QueryResult<Self> {
+        use crate::schema::tasks::dsl::*;
+
+        diesel::update(tasks.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `tasks`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::tasks::dsl::*;
+
+        diesel::delete(tasks.filter(id.eq(param_id))).execute(db)
+    }
+}
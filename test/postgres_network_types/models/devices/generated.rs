@@ -0,0 +1,109 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `devices`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=devices, primary_key(id))]
+pub struct Devices {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `address`
+    pub address: ipnet::IpNet,
+    /// Field representing column `subnet`
+    pub subnet: Option<ipnet::IpNet>,
+    /// Field representing column `mac`
+    pub mac: [u8; 6],
+    /// Field representing column `mac8`
+    pub mac8: [u8; 8],
+    /// Field representing column `allowed`
+    pub allowed: Vec<Option<ipnet::IpNet>>,
+}
+
+/// Create Struct for a row in table `devices` for [`Devices`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=devices)]
+pub struct CreateDevices {
+    /// Field representing column `address`
+    pub address: ipnet::IpNet,
+    /// Field representing column `subnet`
+    pub subnet: Option<ipnet::IpNet>,
+    /// Field representing column `mac`
+    pub mac: [u8; 6],
+    /// Field representing column `mac8`
+    pub mac8: [u8; 8],
+    /// Field representing column `allowed`
+    pub allowed: Vec<Option<ipnet::IpNet>>,
+}
+
+/// Update Struct for a row in table `devices` for [`Devices`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=devices)]
+pub struct UpdateDevices {
+    /// Field representing column `address`
+    pub address: Option<ipnet::IpNet>,
+    /// Field representing column `subnet`
+    pub subnet: Option<Option<ipnet::IpNet>>,
+    /// Field representing column `mac`
+    pub mac: Option<[u8; 6]>,
+    /// Field representing column `mac8`
+    pub mac8: Option<[u8; 8]>,
+    /// Field representing column `allowed`
+    pub allowed: Option<Vec<Option<ipnet::IpNet>>>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Devices {
+    /// Insert a new row into `devices` with a given [`CreateDevices`]
+    pub fn create(db: &mut ConnectionType, item: &CreateDevices) -> diesel::QueryResult<Self> {
+        use crate::schema::devices::dsl::*;
+
+        diesel::insert_into(devices).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `devices` with the given [`CreateDevices`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateDevices]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::devices::dsl::*;
+
+        diesel::insert_into(devices).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `devices`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::devices::dsl::*;
+
+        devices.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `devices`, identified by the primary key with [`UpdateDevices`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateDevices) -> diesel::QueryResult<Self> {
+        use crate::schema::devices::dsl::*;
+
+        diesel::update(devices.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `devices`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::devices::dsl::*;
+
+        diesel::delete(devices.filter(id.eq(param_id))).execute(db)
+    }
+}
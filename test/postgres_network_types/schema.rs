@@ -0,0 +1,10 @@
+diesel::table! {
+    devices (id) {
+        id -> Int4,
+        address -> Inet,
+        subnet -> Nullable<Cidr>,
+        mac -> Macaddr,
+        mac8 -> Macaddr8,
+        allowed -> Array<Nullable<Inet>>,
+    }
+}
@@ -0,0 +1,2 @@
+pub mod tenants;
+pub mod tenant;
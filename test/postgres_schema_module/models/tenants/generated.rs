@@ -0,0 +1,85 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `tenants`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=tenants, primary_key(id))]
+pub struct Tenants {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `name`
+    pub name: String,
+}
+
+/// Create Struct for a row in table `tenants` for [`Tenants`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=tenants)]
+pub struct CreateTenants {
+    /// Field representing column `name`
+    pub name: String,
+}
+
+/// Update Struct for a row in table `tenants` for [`Tenants`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=tenants)]
+pub struct UpdateTenants {
+    /// Field representing column `name`
+    pub name: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Tenants {
+    /// Insert a new row into `tenants` with a given [`CreateTenants`]
+    pub fn create(db: &mut ConnectionType, item: &CreateTenants) -> diesel::QueryResult<Self> {
+        use crate::schema::tenants::dsl::*;
+
+        diesel::insert_into(tenants).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `tenants` with the given [`CreateTenants`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTenants]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::tenants::dsl::*;
+
+        diesel::insert_into(tenants).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `tenants`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::tenants::dsl::*;
+
+        tenants.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `tenants`, identified by the primary key with [`UpdateTenants`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateTenants) -> diesel::QueryResult<Self> {
+        use crate::schema::tenants::dsl::*;
+
+        diesel::update(tenants.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `tenants`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::tenants::dsl::*;
+
+        diesel::delete(tenants.filter(id.eq(param_id))).execute(db)
+    }
+}
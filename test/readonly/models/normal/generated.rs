@@ -55,6 +55,13 @@ impl Normal {
         diesel::insert_into(normal).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `normal` with the given [`CreateNormal`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateNormal]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::normal::dsl::*;
+
+        diesel::insert_into(normal).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `normal`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
         use crate::schema::normal::dsl::*;
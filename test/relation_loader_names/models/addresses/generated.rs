@@ -0,0 +1,85 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `addresses`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=addresses, primary_key(id))]
+pub struct Addresses {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `street`
+    pub street: String,
+}
+
+/// Create Struct for a row in table `addresses` for [`Addresses`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=addresses)]
+pub struct CreateAddresses {
+    /// Field representing column `street`
+    pub street: String,
+}
+
+/// Update Struct for a row in table `addresses` for [`Addresses`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=addresses)]
+pub struct UpdateAddresses {
+    /// Field representing column `street`
+    pub street: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Addresses {
+    /// Insert a new row into `addresses` with a given [`CreateAddresses`]
+    pub fn create(db: &mut ConnectionType, item: &CreateAddresses) -> diesel::QueryResult<Self> {
+        use crate::schema::addresses::dsl::*;
+
+        diesel::insert_into(addresses).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `addresses` with the given [`CreateAddresses`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateAddresses]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::addresses::dsl::*;
+
+        diesel::insert_into(addresses).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `addresses`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::addresses::dsl::*;
+
+        addresses.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `addresses`, identified by the primary key with [`UpdateAddresses`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateAddresses) -> diesel::QueryResult<Self> {
+        use crate::schema::addresses::dsl::*;
+
+        diesel::update(addresses.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `addresses`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::addresses::dsl::*;
+
+        diesel::delete(addresses.filter(id.eq(param_id))).execute(db)
+    }
+}
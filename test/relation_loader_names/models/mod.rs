@@ -0,0 +1,5 @@
+pub mod addresses;
+pub mod categories;
+pub mod products;
+pub mod people;
+pub mod status;
@@ -0,0 +1,155 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::models::addresses::Addresses;
+use crate::models::categories::Categories;
+use crate::models::people::People;
+use crate::models::status::Status;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `products`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Associations, diesel::Identifiable)]
+#[diesel(table_name=products, primary_key(id), belongs_to(Addresses, foreign_key=address_id) , belongs_to(Categories, foreign_key=category_id) , belongs_to(People, foreign_key=person_id) , belongs_to(Status, foreign_key=status_id))]
+pub struct Products {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `address_id`
+    pub address_id: i32,
+    /// Field representing column `category_id`
+    pub category_id: i32,
+    /// Field representing column `person_id`
+    pub person_id: i32,
+    /// Field representing column `status_id`
+    pub status_id: i32,
+}
+
+/// Create Struct for a row in table `products` for [`Products`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=products)]
+pub struct CreateProducts {
+    /// Field representing column `address_id`
+    pub address_id: i32,
+    /// Field representing column `category_id`
+    pub category_id: i32,
+    /// Field representing column `person_id`
+    pub person_id: i32,
+    /// Field representing column `status_id`
+    pub status_id: i32,
+}
+
+/// Update Struct for a row in table `products` for [`Products`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=products)]
+pub struct UpdateProducts {
+    /// Field representing column `address_id`
+    pub address_id: Option<i32>,
+    /// Field representing column `category_id`
+    pub category_id: Option<i32>,
+    /// Field representing column `person_id`
+    pub person_id: Option<i32>,
+    /// Field representing column `status_id`
+    pub status_id: Option<i32>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Products {
+    /// Insert a new row into `products` with a given [`CreateProducts`]
+    pub fn create(db: &mut ConnectionType, item: &CreateProducts) -> diesel::QueryResult<Self> {
+        use crate::schema::products::dsl::*;
+
+        diesel::insert_into(products).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `products` with the given [`CreateProducts`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateProducts]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::products::dsl::*;
+
+        diesel::insert_into(products).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `products`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::products::dsl::*;
+
+        products.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Get all rows of `products` which belong to the given [`Addresses`] (by `address_id`)
+    pub fn for_addresses(db: &mut ConnectionType, parent: &Addresses) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).load::<Self>(db)
+    }
+
+    /// Get all rows of `products` which belong to any of the given [`Addresses`] (by `address_id`), grouped by their parent
+    pub fn for_addresses_grouped(db: &mut ConnectionType, parents: &[Addresses]) -> diesel::QueryResult<Vec<(Addresses, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
+    /// Get all rows of `products` which belong to the given [`Categories`] (by `category_id`)
+    pub fn for_category(db: &mut ConnectionType, parent: &Categories) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).load::<Self>(db)
+    }
+
+    /// Get all rows of `products` which belong to any of the given [`Categories`] (by `category_id`), grouped by their parent
+    pub fn for_categories(db: &mut ConnectionType, parents: &[Categories]) -> diesel::QueryResult<Vec<(Categories, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
+    /// Get all rows of `products` which belong to the given [`People`] (by `person_id`)
+    pub fn for_person(db: &mut ConnectionType, parent: &People) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).load::<Self>(db)
+    }
+
+    /// Get all rows of `products` which belong to any of the given [`People`] (by `person_id`), grouped by their parent
+    pub fn for_people(db: &mut ConnectionType, parents: &[People]) -> diesel::QueryResult<Vec<(People, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
+    /// Get all rows of `products` which belong to the given [`Status`] (by `status_id`)
+    pub fn for_status(db: &mut ConnectionType, parent: &Status) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).load::<Self>(db)
+    }
+
+    /// Get all rows of `products` which belong to any of the given [`Status`] (by `status_id`), grouped by their parent
+    pub fn for_status_grouped(db: &mut ConnectionType, parents: &[Status]) -> diesel::QueryResult<Vec<(Status, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
+    /// Update a row in `products`, identified by the primary key with [`UpdateProducts`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateProducts) -> diesel::QueryResult<Self> {
+        use crate::schema::products::dsl::*;
+
+        diesel::update(products.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `products`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::products::dsl::*;
+
+        diesel::delete(products.filter(id.eq(param_id))).execute(db)
+    }
+}
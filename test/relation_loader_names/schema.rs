@@ -0,0 +1,44 @@
+diesel::table! {
+    addresses (id) {
+        id -> Int4,
+        street -> Text,
+    }
+}
+
+diesel::table! {
+    categories (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    products (id) {
+        id -> Int4,
+        address_id -> Int4,
+        category_id -> Int4,
+        person_id -> Int4,
+        status_id -> Int4,
+    }
+}
+
+diesel::table! {
+    people (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    status (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::joinable!(products -> addresses (address_id));
+diesel::joinable!(products -> categories (category_id));
+diesel::joinable!(products -> people (person_id));
+diesel::joinable!(products -> status (status_id));
+
+diesel::allow_tables_to_appear_in_same_query!(addresses, categories, products, people, status,);
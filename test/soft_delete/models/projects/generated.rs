@@ -0,0 +1,297 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `projects`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=projects, primary_key(id))]
+pub struct Projects {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `name`
+    pub name: String,
+}
+
+/// Create Struct for a row in table `projects` for [`Projects`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=projects)]
+pub struct CreateProjects {
+    /// Field representing column `name`
+    pub name: String,
+}
+
+/// Update Struct for a row in table `projects` for [`Projects`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=projects)]
+pub struct UpdateProjects {
+    /// Field representing column `name`
+    pub name: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+/// Result of a `.paginate_after` function
+#[derive(Debug, serde::Serialize)]
+pub struct CursorPaginationResult<T, C> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// Cursor to get the next page with, `None` if this is the last page
+    pub next_cursor: Option<C>,
+    /// Size of a page
+    pub page_size: i64,
+}
+
+/// Direction to order by, used by the `OrderBy` structs
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Ascending order (smallest first)
+    #[default]
+    Asc,
+    /// Descending order (largest first)
+    Desc,
+}
+
+impl Projects {
+    /// Insert a new row into `projects` with a given [`CreateProjects`]
+    pub fn create(db: &mut ConnectionType, item: &CreateProjects) -> diesel::QueryResult<Self> {
+        use crate::schema::projects::dsl::*;
+
+        diesel::insert_into(projects).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `projects` with the given [`CreateProjects`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateProjects]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::projects::dsl::*;
+
+        diesel::insert_into(projects).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `projects`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::projects::dsl::*;
+
+        projects.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut ConnectionType, page: i64, page_size: i64, filter: ProjectsFilter) -> diesel::QueryResult<PaginationResult<Self>> {
+        Self::paginate_ordered(db, page, page_size, filter, ProjectsOrderBy::default())
+    }
+
+    /// Same as [`Self::paginate`], but the pages are ordered by `order_by`
+    pub fn paginate_ordered(db: &mut ConnectionType, page: i64, page_size: i64, filter: ProjectsFilter, order_by: ProjectsOrderBy) -> diesel::QueryResult<PaginationResult<Self>> {
+        let page = page.max(0);
+        let page_size = page_size.max(1);
+        let total_items = Self::filter(filter.clone()).count().get_result(db)?;
+        let items = Self::filter_ordered(filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by `id`, starting after the given cursor (`None` for the first page)
+    ///
+    /// Unlike `paginate`, this does not count all rows and does not use a offset, which makes it suitable for large tables
+    pub fn paginate_after(db: &mut ConnectionType, cursor: Option<ProjectsCursor>, page_size: i64, filter: ProjectsFilter) -> diesel::QueryResult<CursorPaginationResult<Self, ProjectsCursor>> {
+        let page_size = page_size.max(1);
+        let mut query = Self::filter(filter);
+
+        if let Some(cursor) = &cursor {
+            query = query.filter(crate::schema::projects::id.gt(&cursor.id));
+        }
+
+        let items = query.order(crate::schema::projects::id.asc()).limit(page_size).load::<Self>(db)?;
+        let next_cursor = if items.len() as i64 == page_size {
+            items.last().map(|item| ProjectsCursor { id: item.id.clone() })
+        } else {
+            None
+        };
+
+        Ok(CursorPaginationResult {
+            items,
+            next_cursor,
+            page_size,
+        })
+    }
+
+    /// A utility function to help build custom search queries
+    /// 
+    /// Example:
+    /// 
+    /// ```
+    /// // create a filter for completed todos
+    /// let query = Todo::filter(TodoFilter {
+    ///     completed: Some(true),
+    ///     ..Default::default()
+    /// });
+    /// 
+    /// // delete completed todos
+    /// diesel::delete(query).execute(db)?;
+    /// ```
+    ///
+    /// The query is not ordered, use [`Self::filter_ordered`] or [`ProjectsOrderBy::apply`] to order it
+    pub fn filter<'a>(
+        filter: ProjectsFilter,
+    ) -> crate::schema::projects::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = crate::schema::projects::table.into_boxed();
+        
+        if let Some(filter_id) = filter.id {
+            query = query.filter(crate::schema::projects::id.eq(filter_id));
+        }
+        if let Some(filter_id_in) = filter.id_in {
+            query = query.filter(crate::schema::projects::id.eq_any(filter_id_in));
+        }
+        if let Some(filter_id_gt) = filter.id_gt {
+            query = query.filter(crate::schema::projects::id.gt(filter_id_gt));
+        }
+        if let Some(filter_id_gte) = filter.id_gte {
+            query = query.filter(crate::schema::projects::id.ge(filter_id_gte));
+        }
+        if let Some(filter_id_lt) = filter.id_lt {
+            query = query.filter(crate::schema::projects::id.lt(filter_id_lt));
+        }
+        if let Some(filter_id_lte) = filter.id_lte {
+            query = query.filter(crate::schema::projects::id.le(filter_id_lte));
+        }
+        if let Some(filter_name) = filter.name {
+            query = query.filter(crate::schema::projects::name.eq(filter_name));
+        }
+        if let Some(filter_name_in) = filter.name_in {
+            query = query.filter(crate::schema::projects::name.eq_any(filter_name_in));
+        }
+        if let Some(filter_name_like) = filter.name_like {
+            query = query.filter(crate::schema::projects::name.like(filter_name_like));
+        }
+        if let Some(filter_name_ilike) = filter.name_ilike {
+            query = query.filter(crate::schema::projects::name.ilike(filter_name_ilike));
+        }
+        
+        query
+    }
+
+    /// Same as [`Self::filter`], but ordered by `order_by`
+    pub fn filter_ordered<'a>(
+        filter: ProjectsFilter,
+        order_by: ProjectsOrderBy,
+    ) -> crate::schema::projects::BoxedQuery<'a, diesel::pg::Pg> {
+        order_by.apply(Self::filter(filter))
+    }
+
+    /// Update a row in `projects`, identified by the primary key with [`UpdateProjects`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateProjects) -> diesel::QueryResult<Self> {
+        use crate::schema::projects::dsl::*;
+
+        diesel::update(projects.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `projects`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::projects::dsl::*;
+
+        diesel::delete(projects.filter(id.eq(param_id))).execute(db)
+    }
+}
+#[derive(Debug, Default, Clone)]
+pub struct ProjectsFilter {
+    pub id: Option<i32>,
+    pub id_in: Option<Vec<i32>>,
+    pub id_gt: Option<i32>,
+    pub id_gte: Option<i32>,
+    pub id_lt: Option<i32>,
+    pub id_lte: Option<i32>,
+    pub name: Option<String>,
+    pub name_in: Option<Vec<String>>,
+    pub name_like: Option<String>,
+    pub name_ilike: Option<String>,
+}
+
+/// Position after the last row of a page of [`Projects::paginate_after`], to get the next page with
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ProjectsCursor {
+    pub id: i32,
+}
+
+/// All columns of `projects`, for example to order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectsColumn {
+    /// Column `id`
+    Id,
+    /// Column `name`
+    Name,
+}
+
+impl std::str::FromStr for ProjectsColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "id" => Ok(Self::Id),
+            "name" => Ok(Self::Name),
+            _ => Err(format!("unknown column \"{s}\" in table \"projects\"")),
+        }
+    }
+}
+
+/// Ordering for queries of `projects`, by one or multiple columns (the first having the highest priority)
+///
+/// Can be parsed from a comma-separated list of columns, where a `-` prefix means descending order (like `-created_at,title`)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProjectsOrderBy(pub Vec<(ProjectsColumn, SortDirection)>);
+
+impl ProjectsOrderBy {
+    /// Apply the ordering to a query of `projects`, like the one from [`Projects::filter`]
+    pub fn apply<'a>(
+        &self,
+        mut query: crate::schema::projects::BoxedQuery<'a, diesel::pg::Pg>,
+    ) -> crate::schema::projects::BoxedQuery<'a, diesel::pg::Pg> {
+        for (column, direction) in self.0.iter() {
+            query = match (column, direction) {
+                (ProjectsColumn::Id, SortDirection::Asc) => query.then_order_by(crate::schema::projects::id.asc()),
+                (ProjectsColumn::Id, SortDirection::Desc) => query.then_order_by(crate::schema::projects::id.desc()),
+                (ProjectsColumn::Name, SortDirection::Asc) => query.then_order_by(crate::schema::projects::name.asc()),
+                (ProjectsColumn::Name, SortDirection::Desc) => query.then_order_by(crate::schema::projects::name.desc()),
+            };
+        }
+
+        query
+    }
+}
+
+impl std::str::FromStr for ProjectsOrderBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| match v.strip_prefix('-') {
+                Some(column) => Ok((column.parse()?, SortDirection::Desc)),
+                None => Ok((v.strip_prefix('+').unwrap_or(v).parse()?, SortDirection::Asc)),
+            })
+            .collect::<std::result::Result<Vec<_>, Self::Err>>()
+            .map(Self)
+    }
+}
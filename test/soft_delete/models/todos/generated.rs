@@ -0,0 +1,444 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::models::projects::Projects;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `todos`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Associations, diesel::Identifiable)]
+#[diesel(table_name=todos, primary_key(id), belongs_to(Projects, foreign_key=project_id))]
+pub struct Todos {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `project_id`
+    pub project_id: i32,
+    /// Field representing column `text`
+    pub text: String,
+    /// Field representing column `deleted_at`
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Create Struct for a row in table `todos` for [`Todos`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=todos)]
+pub struct CreateTodos {
+    /// Field representing column `project_id`
+    pub project_id: i32,
+    /// Field representing column `text`
+    pub text: String,
+    /// Field representing column `deleted_at`
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Update Struct for a row in table `todos` for [`Todos`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=todos)]
+pub struct UpdateTodos {
+    /// Field representing column `project_id`
+    pub project_id: Option<i32>,
+    /// Field representing column `text`
+    pub text: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+/// Result of a `.paginate_after` function
+#[derive(Debug, serde::Serialize)]
+pub struct CursorPaginationResult<T, C> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// Cursor to get the next page with, `None` if this is the last page
+    pub next_cursor: Option<C>,
+    /// Size of a page
+    pub page_size: i64,
+}
+
+/// Direction to order by, used by the `OrderBy` structs
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Ascending order (smallest first)
+    #[default]
+    Asc,
+    /// Descending order (largest first)
+    Desc,
+}
+
+impl Todos {
+    /// Insert a new row into `todos` with a given [`CreateTodos`]
+    pub fn create(db: &mut ConnectionType, item: &CreateTodos) -> diesel::QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::insert_into(todos).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `todos` with the given [`CreateTodos`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateTodos]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::insert_into(todos).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `todos`, identified by the primary key, excluding soft-deleted rows
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        todos.filter(id.eq(param_id)).filter(deleted_at.is_null()).first::<Self>(db)
+    }
+
+    /// Get a row from `todos`, identified by the primary key, including soft-deleted rows
+    pub fn read_with_deleted(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        todos.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Get all rows of `todos` which belong to the given [`Projects`] (by `project_id`), excluding soft-deleted rows
+    pub fn for_projects(db: &mut ConnectionType, parent: &Projects) -> diesel::QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).filter(crate::schema::todos::deleted_at.is_null()).load::<Self>(db)
+    }
+
+    /// Get all rows of `todos` which belong to any of the given [`Projects`] (by `project_id`), grouped by their parent, excluding soft-deleted rows
+    pub fn for_projects_grouped(db: &mut ConnectionType, parents: &[Projects]) -> diesel::QueryResult<Vec<(Projects, Vec<Self>)>> {
+        let children = Self::belonging_to(parents).filter(crate::schema::todos::deleted_at.is_null()).load::<Self>(db)?;
+
+        Ok(parents.iter().cloned().zip(children.grouped_by(parents)).collect())
+    }
+
+    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page), excluding soft-deleted rows
+    pub fn paginate(db: &mut ConnectionType, page: i64, page_size: i64, filter: TodosFilter) -> diesel::QueryResult<PaginationResult<Self>> {
+        Self::paginate_ordered(db, page, page_size, filter, TodosOrderBy::default())
+    }
+
+    /// Same as [`Self::paginate`], but the pages are ordered by `order_by`
+    pub fn paginate_ordered(db: &mut ConnectionType, page: i64, page_size: i64, filter: TodosFilter, order_by: TodosOrderBy) -> diesel::QueryResult<PaginationResult<Self>> {
+        let page = page.max(0);
+        let page_size = page_size.max(1);
+        let total_items = Self::filter(filter.clone()).count().get_result(db)?;
+        let items = Self::filter_ordered(filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page), including soft-deleted rows
+    pub fn paginate_with_deleted(db: &mut ConnectionType, page: i64, page_size: i64, filter: TodosFilter) -> diesel::QueryResult<PaginationResult<Self>> {
+        Self::paginate_with_deleted_ordered(db, page, page_size, filter, TodosOrderBy::default())
+    }
+
+    /// Same as [`Self::paginate_with_deleted`], but the pages are ordered by `order_by`
+    pub fn paginate_with_deleted_ordered(db: &mut ConnectionType, page: i64, page_size: i64, filter: TodosFilter, order_by: TodosOrderBy) -> diesel::QueryResult<PaginationResult<Self>> {
+        let page = page.max(0);
+        let page_size = page_size.max(1);
+        let total_items = Self::filter_with_deleted(filter.clone()).count().get_result(db)?;
+        let items = Self::filter_with_deleted_ordered(filter, order_by).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by `id`, starting after the given cursor (`None` for the first page)
+    ///
+    /// Unlike `paginate`, this does not count all rows and does not use a offset, which makes it suitable for large tables
+    pub fn paginate_after(db: &mut ConnectionType, cursor: Option<TodosCursor>, page_size: i64, filter: TodosFilter) -> diesel::QueryResult<CursorPaginationResult<Self, TodosCursor>> {
+        let page_size = page_size.max(1);
+        let mut query = Self::filter(filter);
+
+        if let Some(cursor) = &cursor {
+            query = query.filter(crate::schema::todos::id.gt(&cursor.id));
+        }
+
+        let items = query.order(crate::schema::todos::id.asc()).limit(page_size).load::<Self>(db)?;
+        let next_cursor = if items.len() as i64 == page_size {
+            items.last().map(|item| TodosCursor { id: item.id.clone() })
+        } else {
+            None
+        };
+
+        Ok(CursorPaginationResult {
+            items,
+            next_cursor,
+            page_size,
+        })
+    }
+
+    /// A utility function to help build custom search queries
+    /// 
+    /// Example:
+    /// 
+    /// ```
+    /// // create a filter for completed todos
+    /// let query = Todo::filter(TodoFilter {
+    ///     completed: Some(true),
+    ///     ..Default::default()
+    /// });
+    /// 
+    /// // delete completed todos
+    /// diesel::delete(query).execute(db)?;
+    /// ```
+    ///
+    /// The query is not ordered, use [`Self::filter_with_deleted_ordered`] or [`TodosOrderBy::apply`] to order it
+    pub fn filter_with_deleted<'a>(
+        filter: TodosFilter,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = crate::schema::todos::table.into_boxed();
+        
+        if let Some(filter_id) = filter.id {
+            query = query.filter(crate::schema::todos::id.eq(filter_id));
+        }
+        if let Some(filter_id_in) = filter.id_in {
+            query = query.filter(crate::schema::todos::id.eq_any(filter_id_in));
+        }
+        if let Some(filter_id_gt) = filter.id_gt {
+            query = query.filter(crate::schema::todos::id.gt(filter_id_gt));
+        }
+        if let Some(filter_id_gte) = filter.id_gte {
+            query = query.filter(crate::schema::todos::id.ge(filter_id_gte));
+        }
+        if let Some(filter_id_lt) = filter.id_lt {
+            query = query.filter(crate::schema::todos::id.lt(filter_id_lt));
+        }
+        if let Some(filter_id_lte) = filter.id_lte {
+            query = query.filter(crate::schema::todos::id.le(filter_id_lte));
+        }
+        if let Some(filter_project_id) = filter.project_id {
+            query = query.filter(crate::schema::todos::project_id.eq(filter_project_id));
+        }
+        if let Some(filter_project_id_in) = filter.project_id_in {
+            query = query.filter(crate::schema::todos::project_id.eq_any(filter_project_id_in));
+        }
+        if let Some(filter_project_id_gt) = filter.project_id_gt {
+            query = query.filter(crate::schema::todos::project_id.gt(filter_project_id_gt));
+        }
+        if let Some(filter_project_id_gte) = filter.project_id_gte {
+            query = query.filter(crate::schema::todos::project_id.ge(filter_project_id_gte));
+        }
+        if let Some(filter_project_id_lt) = filter.project_id_lt {
+            query = query.filter(crate::schema::todos::project_id.lt(filter_project_id_lt));
+        }
+        if let Some(filter_project_id_lte) = filter.project_id_lte {
+            query = query.filter(crate::schema::todos::project_id.le(filter_project_id_lte));
+        }
+        if let Some(filter_text) = filter.text {
+            query = query.filter(crate::schema::todos::text.eq(filter_text));
+        }
+        if let Some(filter_text_in) = filter.text_in {
+            query = query.filter(crate::schema::todos::text.eq_any(filter_text_in));
+        }
+        if let Some(filter_text_like) = filter.text_like {
+            query = query.filter(crate::schema::todos::text.like(filter_text_like));
+        }
+        if let Some(filter_text_ilike) = filter.text_ilike {
+            query = query.filter(crate::schema::todos::text.ilike(filter_text_ilike));
+        }
+        if let Some(filter_deleted_at) = filter.deleted_at {
+            query = if filter_deleted_at.is_some() { 
+                query.filter(crate::schema::todos::deleted_at.eq(filter_deleted_at))
+            } else {
+                query.filter(crate::schema::todos::deleted_at.is_null())
+            };
+        }
+        if let Some(filter_deleted_at_in) = filter.deleted_at_in {
+            query = query.filter(crate::schema::todos::deleted_at.eq_any(filter_deleted_at_in));
+        }
+        if let Some(filter_deleted_at_gt) = filter.deleted_at_gt {
+            query = query.filter(crate::schema::todos::deleted_at.gt(filter_deleted_at_gt));
+        }
+        if let Some(filter_deleted_at_gte) = filter.deleted_at_gte {
+            query = query.filter(crate::schema::todos::deleted_at.ge(filter_deleted_at_gte));
+        }
+        if let Some(filter_deleted_at_lt) = filter.deleted_at_lt {
+            query = query.filter(crate::schema::todos::deleted_at.lt(filter_deleted_at_lt));
+        }
+        if let Some(filter_deleted_at_lte) = filter.deleted_at_lte {
+            query = query.filter(crate::schema::todos::deleted_at.le(filter_deleted_at_lte));
+        }
+        if filter.deleted_at_is_not_null {
+            query = query.filter(crate::schema::todos::deleted_at.is_not_null());
+        }
+        
+        query
+    }
+
+    /// Same as [`Self::filter_with_deleted`], but excluding soft-deleted rows
+    pub fn filter<'a>(
+        filter: TodosFilter,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        Self::filter_with_deleted(filter).filter(crate::schema::todos::deleted_at.is_null())
+    }
+
+    /// Same as [`Self::filter`], but ordered by `order_by`
+    pub fn filter_ordered<'a>(
+        filter: TodosFilter,
+        order_by: TodosOrderBy,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        order_by.apply(Self::filter(filter))
+    }
+
+    /// Same as [`Self::filter_with_deleted`], but ordered by `order_by`
+    pub fn filter_with_deleted_ordered<'a>(
+        filter: TodosFilter,
+        order_by: TodosOrderBy,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        order_by.apply(Self::filter_with_deleted(filter))
+    }
+
+    /// Update a row in `todos`, identified by the primary key with [`UpdateTodos`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateTodos) -> diesel::QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::update(todos.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Soft-delete a row in `todos`, identified by the primary key, by setting `deleted_at` to the current time
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::update(todos.filter(id.eq(param_id)).filter(deleted_at.is_null())).set(deleted_at.eq(diesel::dsl::now)).execute(db)
+    }
+
+    /// Restore a soft-deleted row in `todos`, identified by the primary key
+    pub fn restore(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::update(todos.filter(id.eq(param_id))).set(deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>)).execute(db)
+    }
+
+    /// Permanently delete a row in `todos`, identified by the primary key
+    pub fn hard_delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::delete(todos.filter(id.eq(param_id))).execute(db)
+    }
+}
+#[derive(Debug, Default, Clone)]
+pub struct TodosFilter {
+    pub id: Option<i32>,
+    pub id_in: Option<Vec<i32>>,
+    pub id_gt: Option<i32>,
+    pub id_gte: Option<i32>,
+    pub id_lt: Option<i32>,
+    pub id_lte: Option<i32>,
+    pub project_id: Option<i32>,
+    pub project_id_in: Option<Vec<i32>>,
+    pub project_id_gt: Option<i32>,
+    pub project_id_gte: Option<i32>,
+    pub project_id_lt: Option<i32>,
+    pub project_id_lte: Option<i32>,
+    pub text: Option<String>,
+    pub text_in: Option<Vec<String>>,
+    pub text_like: Option<String>,
+    pub text_ilike: Option<String>,
+    pub deleted_at: Option<Option<chrono::DateTime<chrono::Utc>>>,
+    pub deleted_at_in: Option<Vec<chrono::DateTime<chrono::Utc>>>,
+    pub deleted_at_gt: Option<chrono::DateTime<chrono::Utc>>,
+    pub deleted_at_gte: Option<chrono::DateTime<chrono::Utc>>,
+    pub deleted_at_lt: Option<chrono::DateTime<chrono::Utc>>,
+    pub deleted_at_lte: Option<chrono::DateTime<chrono::Utc>>,
+    pub deleted_at_is_not_null: bool,
+}
+
+/// Position after the last row of a page of [`Todos::paginate_after`], to get the next page with
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TodosCursor {
+    pub id: i32,
+}
+
+/// All columns of `todos`, for example to order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodosColumn {
+    /// Column `id`
+    Id,
+    /// Column `project_id`
+    ProjectId,
+    /// Column `text`
+    Text,
+    /// Column `deleted_at`
+    DeletedAt,
+}
+
+impl std::str::FromStr for TodosColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "id" => Ok(Self::Id),
+            "project_id" => Ok(Self::ProjectId),
+            "text" => Ok(Self::Text),
+            "deleted_at" => Ok(Self::DeletedAt),
+            _ => Err(format!("unknown column \"{s}\" in table \"todos\"")),
+        }
+    }
+}
+
+/// Ordering for queries of `todos`, by one or multiple columns (the first having the highest priority)
+///
+/// Can be parsed from a comma-separated list of columns, where a `-` prefix means descending order (like `-created_at,title`)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TodosOrderBy(pub Vec<(TodosColumn, SortDirection)>);
+
+impl TodosOrderBy {
+    /// Apply the ordering to a query of `todos`, like the one from [`Todos::filter`]
+    pub fn apply<'a>(
+        &self,
+        mut query: crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg>,
+    ) -> crate::schema::todos::BoxedQuery<'a, diesel::pg::Pg> {
+        for (column, direction) in self.0.iter() {
+            query = match (column, direction) {
+                (TodosColumn::Id, SortDirection::Asc) => query.then_order_by(crate::schema::todos::id.asc()),
+                (TodosColumn::Id, SortDirection::Desc) => query.then_order_by(crate::schema::todos::id.desc()),
+                (TodosColumn::ProjectId, SortDirection::Asc) => query.then_order_by(crate::schema::todos::project_id.asc()),
+                (TodosColumn::ProjectId, SortDirection::Desc) => query.then_order_by(crate::schema::todos::project_id.desc()),
+                (TodosColumn::Text, SortDirection::Asc) => query.then_order_by(crate::schema::todos::text.asc()),
+                (TodosColumn::Text, SortDirection::Desc) => query.then_order_by(crate::schema::todos::text.desc()),
+                (TodosColumn::DeletedAt, SortDirection::Asc) => query.then_order_by(crate::schema::todos::deleted_at.asc()),
+                (TodosColumn::DeletedAt, SortDirection::Desc) => query.then_order_by(crate::schema::todos::deleted_at.desc()),
+            };
+        }
+
+        query
+    }
+}
+
+impl std::str::FromStr for TodosOrderBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| match v.strip_prefix('-') {
+                Some(column) => Ok((column.parse()?, SortDirection::Desc)),
+                None => Ok((v.strip_prefix('+').unwrap_or(v).parse()?, SortDirection::Asc)),
+            })
+            .collect::<std::result::Result<Vec<_>, Self::Err>>()
+            .map(Self)
+    }
+}
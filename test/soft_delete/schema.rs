@@ -0,0 +1,19 @@
+diesel::table! {
+    projects (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    todos (id) {
+        id -> Int4,
+        project_id -> Int4,
+        text -> Text,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::joinable!(todos -> projects (project_id));
+
+diesel::allow_tables_to_appear_in_same_query!(projects, todos,);
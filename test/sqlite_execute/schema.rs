@@ -0,0 +1,7 @@
+diesel::table! {
+    todos (id) {
+        id -> Integer,
+        text -> Text,
+        completed -> Bool,
+    }
+}
@@ -0,0 +1,103 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `invoices`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=invoices, primary_key(id))]
+pub struct Invoices {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `amount`
+    pub amount: rust_decimal::Decimal,
+    /// Field representing column `tax`
+    pub tax: Option<rust_decimal::Decimal>,
+    /// Field representing column `issued_at`
+    pub issued_at: time::OffsetDateTime,
+    /// Field representing column `due_on`
+    pub due_on: time::Date,
+}
+
+/// Create Struct for a row in table `invoices` for [`Invoices`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=invoices)]
+pub struct CreateInvoices {
+    /// Field representing column `amount`
+    pub amount: rust_decimal::Decimal,
+    /// Field representing column `tax`
+    pub tax: Option<rust_decimal::Decimal>,
+    /// Field representing column `issued_at`
+    pub issued_at: time::OffsetDateTime,
+    /// Field representing column `due_on`
+    pub due_on: time::Date,
+}
+
+/// Update Struct for a row in table `invoices` for [`Invoices`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=invoices)]
+pub struct UpdateInvoices {
+    /// Field representing column `amount`
+    pub amount: Option<rust_decimal::Decimal>,
+    /// Field representing column `tax`
+    pub tax: Option<Option<rust_decimal::Decimal>>,
+    /// Field representing column `issued_at`
+    pub issued_at: Option<time::OffsetDateTime>,
+    /// Field representing column `due_on`
+    pub due_on: Option<time::Date>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Invoices {
+    /// Insert a new row into `invoices` with a given [`CreateInvoices`]
+    pub fn create(db: &mut ConnectionType, item: &CreateInvoices) -> diesel::QueryResult<Self> {
+        use crate::schema::invoices::dsl::*;
+
+        diesel::insert_into(invoices).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `invoices` with the given [`CreateInvoices`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateInvoices]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::invoices::dsl::*;
+
+        diesel::insert_into(invoices).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `invoices`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::invoices::dsl::*;
+
+        invoices.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `invoices`, identified by the primary key with [`UpdateInvoices`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateInvoices) -> diesel::QueryResult<Self> {
+        use crate::schema::invoices::dsl::*;
+
+        diesel::update(invoices.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `invoices`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::invoices::dsl::*;
+
+        diesel::delete(invoices.filter(id.eq(param_id))).execute(db)
+    }
+}
@@ -0,0 +1,9 @@
+diesel::table! {
+    invoices (id) {
+        id -> Int4,
+        amount -> Numeric,
+        tax -> Nullable<Numeric>,
+        issued_at -> Timestamptz,
+        due_on -> Date,
+    }
+}
@@ -0,0 +1,4 @@
+pub mod countries;
+pub mod users;
+pub mod user_countries;
+pub mod sessions;
@@ -0,0 +1,85 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `sessions`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=sessions, primary_key(id))]
+pub struct Sessions {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `token`
+    pub token: String,
+}
+
+/// Create Struct for a row in table `sessions` for [`Sessions`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=sessions)]
+pub struct CreateSessions {
+    /// Field representing column `token`
+    pub token: String,
+}
+
+/// Update Struct for a row in table `sessions` for [`Sessions`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=sessions)]
+pub struct UpdateSessions {
+    /// Field representing column `token`
+    pub token: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Sessions {
+    /// Insert a new row into `sessions` with a given [`CreateSessions`]
+    pub fn create(db: &mut ConnectionType, item: &CreateSessions) -> diesel::QueryResult<Self> {
+        use crate::schema::sessions::dsl::*;
+
+        diesel::insert_into(sessions).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `sessions` with the given [`CreateSessions`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateSessions]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::sessions::dsl::*;
+
+        diesel::insert_into(sessions).values(items).get_results::<Self>(db)
+    }
+
+    /// Get a row from `sessions`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::sessions::dsl::*;
+
+        sessions.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `sessions`, identified by the primary key with [`UpdateSessions`]
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateSessions) -> diesel::QueryResult<Self> {
+        use crate::schema::sessions::dsl::*;
+
+        diesel::update(sessions.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row in `sessions`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::sessions::dsl::*;
+
+        diesel::delete(sessions.filter(id.eq(param_id))).execute(db)
+    }
+}
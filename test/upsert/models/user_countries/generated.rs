@@ -0,0 +1,80 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::pg::PgConnection>>;
+
+/// Struct representing a row in table `user_countries`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=user_countries, primary_key(user_id,country_code))]
+pub struct UserCountries {
+    /// Field representing column `user_id`
+    pub user_id: i32,
+    /// Field representing column `country_code`
+    pub country_code: String,
+}
+
+/// Create Struct for a row in table `user_countries` for [`UserCountries`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=user_countries)]
+pub struct CreateUserCountries {
+    /// Field representing column `user_id`
+    pub user_id: i32,
+    /// Field representing column `country_code`
+    pub country_code: String,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl UserCountries {
+    /// Insert a new row into `user_countries` with a given [`CreateUserCountries`]
+    pub fn create(db: &mut ConnectionType, item: &CreateUserCountries) -> diesel::QueryResult<Self> {
+        use crate::schema::user_countries::dsl::*;
+
+        diesel::insert_into(user_countries).values(item).get_result::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `user_countries` with the given [`CreateUserCountries`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateUserCountries]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::user_countries::dsl::*;
+
+        diesel::insert_into(user_countries).values(items).get_results::<Self>(db)
+    }
+
+    /// Insert a new row into `user_countries` with a given [`CreateUserCountries`], or keep the existing row on a conflict of `user_id`, `country_code`
+    pub fn upsert(db: &mut ConnectionType, item: &CreateUserCountries) -> diesel::QueryResult<Self> {
+        use crate::schema::user_countries::dsl::*;
+
+        diesel::insert_into(user_countries).values(item).on_conflict((user_id, country_code)).do_nothing().execute(db)?;
+        user_countries.filter(user_id.eq(&item.user_id)).filter(country_code.eq(&item.country_code)).first::<Self>(db)
+    }
+
+    /// Get a row from `user_countries`, identified by the primary keys
+    pub fn read(db: &mut ConnectionType, param_user_id: i32, param_country_code: String) -> diesel::QueryResult<Self> {
+        use crate::schema::user_countries::dsl::*;
+
+        user_countries.filter(user_id.eq(param_user_id)).filter(country_code.eq(param_country_code)).first::<Self>(db)
+    }
+
+    /// Delete a row in `user_countries`, identified by the primary keys
+    pub fn delete(db: &mut ConnectionType, param_user_id: i32, param_country_code: String) -> diesel::QueryResult<usize> {
+        use crate::schema::user_countries::dsl::*;
+
+        diesel::delete(user_countries.filter(user_id.eq(param_user_id)).filter(country_code.eq(param_country_code))).execute(db)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod countries;
+pub mod users;
+pub mod user_countries;
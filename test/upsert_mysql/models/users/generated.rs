@@ -0,0 +1,113 @@
+/* @generated and managed by dsync */
+
+#[allow(unused)]
+use crate::diesel::*;
+use crate::schema::*;
+
+pub type ConnectionType = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::mysql::MysqlConnection>>;
+
+/// Struct representing a row in table `users`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Queryable, diesel::Selectable, diesel::QueryableByName, diesel::Identifiable)]
+#[diesel(table_name=users, primary_key(id))]
+pub struct Users {
+    /// Field representing column `id`
+    pub id: i32,
+    /// Field representing column `email`
+    pub email: String,
+    /// Field representing column `name`
+    pub name: String,
+    /// Field representing column `last_login`
+    pub last_login: Option<chrono::NaiveDateTime>,
+}
+
+/// Create Struct for a row in table `users` for [`Users`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::Insertable)]
+#[diesel(table_name=users)]
+pub struct CreateUsers {
+    /// Field representing column `email`
+    pub email: String,
+    /// Field representing column `name`
+    pub name: String,
+    /// Field representing column `last_login`
+    pub last_login: Option<chrono::NaiveDateTime>,
+}
+
+/// Update Struct for a row in table `users` for [`Users`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsChangeset, PartialEq, Default)]
+#[diesel(table_name=users)]
+pub struct UpdateUsers {
+    /// Field representing column `email`
+    pub email: Option<String>,
+    /// Field representing column `name`
+    pub name: Option<String>,
+    /// Field representing column `last_login`
+    pub last_login: Option<Option<chrono::NaiveDateTime>>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, serde::Serialize)]
+pub struct PaginationResult<T> {
+    /// Resulting items that are from the current page
+    pub items: Vec<T>,
+    /// The count of total items there are
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of total possible pages, given the `page_size` and `total_items`
+    pub num_pages: i64,
+}
+
+impl Users {
+    /// Insert a new row into `users` with a given [`CreateUsers`]
+    ///
+    /// The row is read again after inserting, because the backend does not support `RETURNING`
+    pub fn create(db: &mut ConnectionType, item: &CreateUsers) -> diesel::QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        diesel::insert_into(users).values(item).execute(db)?;
+        users.filter(diesel::dsl::sql::<diesel::sql_types::Bool>("`id` = LAST_INSERT_ID()")).first::<Self>(db)
+    }
+
+    /// Insert multiple new rows into `users` with the given [`CreateUsers`]s, returning the number of inserted rows
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateUsers]) -> diesel::QueryResult<usize> {
+        use crate::schema::users::dsl::*;
+
+        diesel::insert_into(users).values(items).execute(db)
+    }
+
+    /// Insert a new row into `users` with a given [`CreateUsers`], or update the existing row on a conflict of `email`
+    ///
+    /// The row is read again afterwards, because the backend does not support `RETURNING`
+    pub fn upsert(db: &mut ConnectionType, item: &CreateUsers) -> diesel::QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        diesel::insert_into(users).values(item).on_conflict(diesel::dsl::DuplicatedKeys).do_update().set((name.eq(&item.name), last_login.eq(&item.last_login))).execute(db)?;
+        users.filter(email.eq(&item.email)).first::<Self>(db)
+    }
+
+    /// Get a row from `users`, identified by the primary key
+    pub fn read(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        users.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Update a row in `users`, identified by the primary key with [`UpdateUsers`]
+    ///
+    /// The row is read again after updating, because the backend does not support `RETURNING`
+    pub fn update(db: &mut ConnectionType, param_id: i32, item: &UpdateUsers) -> diesel::QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        diesel::update(users.filter(id.eq(&param_id))).set(item).execute(db)?;
+        users.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Delete a row in `users`, identified by the primary key
+    pub fn delete(db: &mut ConnectionType, param_id: i32) -> diesel::QueryResult<usize> {
+        use crate::schema::users::dsl::*;
+
+        diesel::delete(users.filter(id.eq(param_id))).execute(db)
+    }
+}
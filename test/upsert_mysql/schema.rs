@@ -0,0 +1,22 @@
+diesel::table! {
+    countries (code) {
+        code -> Varchar,
+        name -> Varchar,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Integer,
+        email -> Varchar,
+        name -> Varchar,
+        last_login -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    user_countries (user_id, country_code) {
+        user_id -> Integer,
+        country_code -> Varchar,
+    }
+}
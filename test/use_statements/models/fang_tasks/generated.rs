@@ -105,6 +105,13 @@ impl FangTasks {
         diesel::insert_into(fang_tasks).values(item).get_result::<Self>(db)
     }
 
+    /// Insert multiple new rows into `fang_tasks` with the given [`CreateFangTasks`]s
+    pub fn create_many(db: &mut ConnectionType, items: &[CreateFangTasks]) -> diesel::QueryResult<Vec<Self>> {
+        use crate::schema::fang_tasks::dsl::*;
+
+        diesel::insert_into(fang_tasks).values(items).get_results::<Self>(db)
+    }
+
     /// Get a row from `fang_tasks`, identified by the primary key
     pub fn read(db: &mut ConnectionType, param_id: uuid::Uuid) -> diesel::QueryResult<Self> {
         use crate::schema::fang_tasks::dsl::*;